@@ -1,8 +1,26 @@
 //! Data structures for acceleration vectors and easy unit conversion.
 
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Index, Mul, Sub};
+
 use crate::properties::gravity_coefficient;
 
-#[derive(Clone, Copy)]
+/// Standard acceleration due to gravity in m/s².
+pub const STANDARD_GRAVITY: f32 = 9.80665;
+
+/// Axis of the lis3dh's frame of reference.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug)]
 /// Single acceleration value.
 pub struct Acceleration {
     /// Discretized measure of acceleration adjusted for [`crate::properties::resolution`].
@@ -20,11 +38,73 @@ impl Acceleration {
         self.value.to_be_bytes()
     }
 
+    /// Creates an `Acceleration` from bytes in the format `[acceleration_upper, acceleration_lower]`. Inverse of [`Acceleration::to_be_bytes`].
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; 2]) -> Self {
+        Acceleration {
+            value: i16::from_be_bytes(bytes),
+        }
+    }
+
+    /// Returns the `Acceleration` as an array of bytes in the format `[acceleration_lower, acceleration_upper]`.
+    #[inline(always)]
+    pub fn to_le_bytes(&self) -> [u8; 2] {
+        self.value.to_le_bytes()
+    }
+
+    /// Creates an `Acceleration` from bytes in the format `[acceleration_lower, acceleration_upper]`. Inverse of [`Acceleration::to_le_bytes`].
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 2]) -> Self {
+        Acceleration {
+            value: i16::from_le_bytes(bytes),
+        }
+    }
+
+    /// Returns the negated `Acceleration`, saturating `i16::MIN` to `i16::MAX`.
+    fn saturating_neg(self) -> Self {
+        Acceleration::new(self.value.saturating_neg())
+    }
+
     /// Converts acceleration from resolution adjusted i16 to units of gravity.
     pub fn as_g<G: gravity_coefficient::Property>(&self) -> f32 {
         (self.value as f32) * G::GRAVITY_COEFFICIENT
     }
+
+    /// Converts acceleration from resolution adjusted i16 to milli-g using only integer math, for targets without an FPU.
+    ///
+    /// Every gravity coefficient is an exact number of milli-g per digit, so the result is exact and matches [`Acceleration::as_g`] scaled by 1000.
+    pub fn as_milli_g_i32<G: gravity_coefficient::Property>(&self) -> i32 {
+        self.value as i32 * G::MILLI_G_PER_DIGIT as i32
+    }
+
+    /// Converts acceleration from resolution adjusted i16 to m/s² using [`STANDARD_GRAVITY`].
+    ///
+    /// ```
+    /// use lis3dh_driver::acceleration_data_structs::Acceleration;
+    /// use lis3dh_driver::properties::{gravity_coefficient::GravityCoefficient, resolution::Resolution};
+    /// use lis3dh_driver::registers::{ctrl_reg1::lp_en::NormalPowerMode, ctrl_reg4::{fs::S2G, hr::HighResolution}};
+    ///
+    /// type G = GravityCoefficient<S2G, Resolution<NormalPowerMode, HighResolution>>;
+    ///
+    /// // 1000 digits at ±2 g in high-resolution mode is 1 g.
+    /// let one_g = Acceleration { value: 1000 };
+    /// assert!((one_g.as_mps2::<G>() - 9.80665).abs() < 1e-4);
+    /// ```
+    pub fn as_mps2<G: gravity_coefficient::Property>(&self) -> f32 {
+        self.as_g::<G>() * STANDARD_GRAVITY
+    }
+
+    /// Converts acceleration from resolution adjusted i16 to units of gravity in signed Q(31 - FRAC).FRAC fixed-point using only integer math.
+    ///
+    /// `FRAC` may be 0 to 31; the result is rounded toward zero. The largest reading the lis3dh can produce is ~24.6 g, so values never saturate for `FRAC <= 25`. Above that, values outside the representable range saturate at `i32::MIN`/`i32::MAX`.
+    pub fn as_g_qformat<G: gravity_coefficient::Property, const FRAC: u32>(&self) -> i32 {
+        const { core::assert!(FRAC <= 31, "FRAC must be in 0..=31") };
+        let milli_g = self.value as i64 * G::MILLI_G_PER_DIGIT as i64;
+        let q_value = (milli_g << FRAC) / 1000;
+        q_value.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
 }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy)]
 /// 3-axis acceleration vector.
 pub struct AccelerationVector {
@@ -33,7 +113,60 @@ pub struct AccelerationVector {
     pub z: Acceleration,
 }
 
+/// Prints the raw value of each axis on one line, e.g. `AccelerationVector { x: 12, y: -4, z: 1000 }`.
+impl fmt::Debug for AccelerationVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccelerationVector")
+            .field("x", &self.x.value)
+            .field("y", &self.y.value)
+            .field("z", &self.z.value)
+            .finish()
+    }
+}
+
+/// Prints the raw value of each axis on one line, matching the `Debug` output.
+#[cfg(feature = "defmt")]
+impl defmt::Format for AccelerationVector {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "AccelerationVector {{ x: {=i16}, y: {=i16}, z: {=i16} }}",
+            self.x.value,
+            self.y.value,
+            self.z.value
+        )
+    }
+}
+
 impl AccelerationVector {
+    /// Returns [`ZERO_ACCELERATION_VECTOR`].
+    pub const fn zero() -> Self {
+        ZERO_ACCELERATION_VECTOR
+    }
+
+    /// Returns the axes in the order `[x, y, z]`.
+    pub const fn axes(&self) -> [Acceleration; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Applies `f` to each axis' raw value.
+    fn map(self, f: impl Fn(i16) -> i16) -> Self {
+        AccelerationVector {
+            x: Acceleration::new(f(self.x.value)),
+            y: Acceleration::new(f(self.y.value)),
+            z: Acceleration::new(f(self.z.value)),
+        }
+    }
+
+    /// Combines each axis' raw value with the matching axis of `other` using `f`.
+    fn zip_with(self, other: Self, f: impl Fn(i16, i16) -> i16) -> Self {
+        AccelerationVector {
+            x: Acceleration::new(f(self.x.value, other.x.value)),
+            y: Acceleration::new(f(self.y.value, other.y.value)),
+            z: Acceleration::new(f(self.z.value, other.z.value)),
+        }
+    }
+
     /// Returns the `AccelerationVector` as an array of bytes in the format `[x_upper, x_lower, y_upper, y_lower, z_upper, z_lower]`.
     #[inline(always)]
     pub fn to_be_bytes(&self) -> [u8; 6] {
@@ -55,6 +188,229 @@ impl AccelerationVector {
             a_z_bytes_lower,
         ]
     }
+
+    /// Creates an `AccelerationVector` from bytes in the format `[x_upper, x_lower, y_upper, y_lower, z_upper, z_lower]`. Inverse of [`AccelerationVector::to_be_bytes`].
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; 6]) -> Self {
+        let [x_upper, x_lower, y_upper, y_lower, z_upper, z_lower] = bytes;
+        AccelerationVector {
+            x: Acceleration::from_be_bytes([x_upper, x_lower]),
+            y: Acceleration::from_be_bytes([y_upper, y_lower]),
+            z: Acceleration::from_be_bytes([z_upper, z_lower]),
+        }
+    }
+
+    /// Returns the `AccelerationVector` as an array of bytes in the format `[x_lower, x_upper, y_lower, y_upper, z_lower, z_upper]`.
+    #[inline(always)]
+    pub fn to_le_bytes(&self) -> [u8; 6] {
+        let [[x_lower, x_upper], [y_lower, y_upper], [z_lower, z_upper]] =
+            self.axes().map(|a| a.to_le_bytes());
+        [x_lower, x_upper, y_lower, y_upper, z_lower, z_upper]
+    }
+
+    /// Creates an `AccelerationVector` from bytes in the format `[x_lower, x_upper, y_lower, y_upper, z_lower, z_upper]`. Inverse of [`AccelerationVector::to_le_bytes`].
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 6]) -> Self {
+        let [x_lower, x_upper, y_lower, y_upper, z_lower, z_upper] = bytes;
+        AccelerationVector {
+            x: Acceleration::from_le_bytes([x_lower, x_upper]),
+            y: Acceleration::from_le_bytes([y_lower, y_upper]),
+            z: Acceleration::from_le_bytes([z_lower, z_upper]),
+        }
+    }
+
+    /// Converts each axis from resolution adjusted i16 to m/s², in the format `[x, y, z]`.
+    pub fn as_mps2<G: gravity_coefficient::Property>(&self) -> [f32; 3] {
+        self.axes().map(|a| a.as_mps2::<G>())
+    }
+
+    /// Returns a wrapper that displays the vector in units of gravity, e.g. `(0.012, -0.004, 1.000) g`.
+    ///
+    /// Each axis is printed with 3 decimal places unless the format string sets a precision, e.g. `{:.1}`.
+    pub fn display_g<G: gravity_coefficient::Property>(&self) -> AccelerationVectorG<'_, G> {
+        AccelerationVectorG {
+            vector: self,
+            _g: PhantomData,
+        }
+    }
+
+    /// Returns the Euclidean norm `sqrt(x² + y² + z²)` of the vector in units of gravity.
+    #[cfg(feature = "libm")]
+    pub fn magnitude_g<G: gravity_coefficient::Property>(&self) -> f32 {
+        let [x, y, z] = self.axes().map(|a| a.as_g::<G>());
+        libm::sqrtf(x * x + y * y + z * z)
+    }
+
+    /// Returns the tilt angles in degrees, where `roll = atan2(y, z)` and `pitch = atan2(-x, sqrt(y² + z²))`.
+    ///
+    /// Angles are relative to the lis3dh lying flat with +Z pointing up, i.e. reading +1 g on Z at rest: pitch is the rotation about the Y axis, positive when the +X axis tilts down, and roll the rotation about the X axis, positive when the +Y axis tilts up. Gravity must be the only acceleration acting on the sensor, so the result is only meaningful in quasi-static conditions.
+    #[cfg(feature = "libm")]
+    pub fn tilt_angles_deg<G: gravity_coefficient::Property>(&self) -> TiltAngles {
+        let [x, y, z] = self.axes().map(|a| a.as_g::<G>());
+        TiltAngles {
+            roll_deg: libm::atan2f(y, z).to_degrees(),
+            pitch_deg: libm::atan2f(-x, libm::sqrtf(y * y + z * z)).to_degrees(),
+        }
+    }
+
+    /// Converts each axis to units of gravity as an `nalgebra` column vector `[x, y, z]`, e.g. to feed a fusion filter.
+    #[cfg(feature = "nalgebra")]
+    pub fn to_vector3<G: gravity_coefficient::Property>(&self) -> nalgebra::Vector3<f32> {
+        let [x, y, z] = self.axes().map(|a| a.as_g::<G>());
+        nalgebra::Vector3::new(x, y, z)
+    }
+
+    /// Rotates the vector +90° about the X axis (right-hand rule): `(x, y, z) -> (x, -z, y)`.
+    ///
+    /// The rotation helpers only swap and negate axes, so they are exact. Negation saturates, so an axis reading `i16::MIN` becomes `i16::MAX`.
+    pub fn rotate_x_90(&self) -> Self {
+        AccelerationVector {
+            x: self.x,
+            y: self.z.saturating_neg(),
+            z: self.y,
+        }
+    }
+
+    /// Rotates the vector -90° about the X axis (right-hand rule): `(x, y, z) -> (x, z, -y)`.
+    pub fn rotate_x_neg_90(&self) -> Self {
+        AccelerationVector {
+            x: self.x,
+            y: self.z,
+            z: self.y.saturating_neg(),
+        }
+    }
+
+    /// Rotates the vector +90° about the Y axis (right-hand rule): `(x, y, z) -> (z, y, -x)`.
+    pub fn rotate_y_90(&self) -> Self {
+        AccelerationVector {
+            x: self.z,
+            y: self.y,
+            z: self.x.saturating_neg(),
+        }
+    }
+
+    /// Rotates the vector -90° about the Y axis (right-hand rule): `(x, y, z) -> (-z, y, x)`.
+    pub fn rotate_y_neg_90(&self) -> Self {
+        AccelerationVector {
+            x: self.z.saturating_neg(),
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    /// Rotates the vector +90° about the Z axis (right-hand rule): `(x, y, z) -> (-y, x, z)`.
+    pub fn rotate_z_90(&self) -> Self {
+        AccelerationVector {
+            x: self.y.saturating_neg(),
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    /// Rotates the vector -90° about the Z axis (right-hand rule): `(x, y, z) -> (y, -x, z)`.
+    pub fn rotate_z_neg_90(&self) -> Self {
+        AccelerationVector {
+            x: self.y,
+            y: self.x.saturating_neg(),
+            z: self.z,
+        }
+    }
+}
+
+/// Indexes the axes in the order `0 => x`, `1 => y`, `2 => z`.
+///
+/// # Panics
+/// Panics if `index` is greater than 2, like slice indexing.
+impl Index<usize> for AccelerationVector {
+    type Output = Acceleration;
+
+    fn index(&self, index: usize) -> &Acceleration {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("axis index out of range: the index is {index} but there are 3 axes"),
+        }
+    }
+}
+
+/// Iterates over the axes in the order `x`, `y`, `z`.
+impl IntoIterator for AccelerationVector {
+    type Item = Acceleration;
+    type IntoIter = core::array::IntoIter<Acceleration, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.axes().into_iter()
+    }
+}
+
+/// [`AccelerationVector`] displayed in units of gravity, returned by [`AccelerationVector::display_g`].
+pub struct AccelerationVectorG<'a, G: gravity_coefficient::Property> {
+    vector: &'a AccelerationVector,
+    _g: PhantomData<G>,
+}
+
+impl<G: gravity_coefficient::Property> fmt::Display for AccelerationVectorG<'_, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+        let [x, y, z] = self.vector.axes().map(|a| a.as_g::<G>());
+        write!(f, "({x:.precision$}, {y:.precision$}, {z:.precision$}) g")
+    }
+}
+
+/// Tilt angles of the lis3dh relative to lying flat, returned by [`AccelerationVector::tilt_angles_deg`].
+#[cfg(feature = "libm")]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TiltAngles {
+    /// Rotation about the X axis in degrees, positive when the +Y axis tilts up.
+    pub roll_deg: f32,
+    /// Rotation about the Y axis in degrees, positive when the +X axis tilts down.
+    pub pitch_deg: f32,
+}
+
+/// Component-wise addition, saturating at `i16::MIN`/`i16::MAX`.
+impl Add for AccelerationVector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.zip_with(rhs, i16::saturating_add)
+    }
+}
+
+/// Component-wise subtraction, saturating at `i16::MIN`/`i16::MAX`.
+impl Sub for AccelerationVector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.zip_with(rhs, i16::saturating_sub)
+    }
+}
+
+/// Component-wise scalar multiplication, saturating at `i16::MIN`/`i16::MAX`.
+impl Mul<i32> for AccelerationVector {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        self.map(|value| saturate_i16((value as i32).saturating_mul(rhs)))
+    }
+}
+
+/// Component-wise scalar division, rounding toward zero and saturating at `i16::MIN`/`i16::MAX`.
+///
+/// # Panics
+/// Panics if `rhs` is zero.
+impl Div<i32> for AccelerationVector {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self {
+        self.map(|value| saturate_i16(value as i32 / rhs))
+    }
+}
+
+fn saturate_i16(value: i32) -> i16 {
+    value.clamp(i16::MIN as i32, i16::MAX as i32) as i16
 }
 
 pub const ZERO_ACCELERATION_VECTOR: AccelerationVector = AccelerationVector {
@@ -0,0 +1,114 @@
+//! Blocking counterpart of [`crate::Lis3dh`] for applications that can't use `async`, e.g. a bare superloop over blocking `embedded-hal` drivers.
+//! The register and config modules are shared with the async driver.
+
+use crate::acceleration_data_structs::AccelerationVector;
+use crate::bus::BlockingLis3dhBus;
+use crate::config::{self, ValidLis3dhConfig};
+use crate::registers::who_am_i::DeviceId;
+use crate::registers::{ctrl_reg1, ReadOnlyRegisterAddress, ReadWriteRegisterAddress};
+use crate::{accel_bytes_into_vector, Error, LIS3DH_DEVICE_ID};
+
+/// Writes the rendered register values of a configuration to the lis3dh.
+fn write_config_bytes<Bus: BlockingLis3dhBus>(
+    bus: &mut Bus,
+    config_bytes: config::ConfigAsBytes,
+) -> Result<(), Error<Bus::BusError>> {
+    // Write Block 1: CtrlReg0 (0x1E) to CtrlReg6 (0x25)
+    // SAFETY: Starting memory address `CtrlReg0 = 0x1E` incremented 7 times leads to `CtrlReg6 = 0x25` which are all writable memory addresses.
+    unsafe {
+        bus.write_multiple(
+            ReadWriteRegisterAddress::CtrlReg0,
+            &config_bytes.ctrl_reg0_to_ctrl_reg6(),
+        )?
+    };
+
+    // Write Block 2: FifoCtrlReg (0x2E)
+    bus.write(
+        ReadWriteRegisterAddress::FifoCtrlReg,
+        config_bytes.fifo_ctrl_reg,
+    )?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(not(feature = "defmt"), expect(unused))]
+pub struct Lis3dhBlocking<Bus, Config>
+where
+    Bus: BlockingLis3dhBus,
+    Config: ValidLis3dhConfig,
+{
+    bus: Bus,
+    config: Config, // Maybe needed in the future.
+}
+
+impl<Bus, Config> Lis3dhBlocking<Bus, Config>
+where
+    Bus: BlockingLis3dhBus,
+    Config: ValidLis3dhConfig,
+{
+    /// Verifies the device on the bus identifies as a lis3dh, then writes the config to it, see [`crate::Lis3dh::new`].
+    pub fn new(mut bus: Bus, config: Config) -> Result<Self, Error<Bus::BusError>> {
+        let who_am_i = bus.read(ReadOnlyRegisterAddress::WhoAmI)?;
+        if who_am_i != LIS3DH_DEVICE_ID {
+            return Err(Error::InvalidDevice { found: who_am_i });
+        }
+        Self::new_unchecked(bus, config)
+    }
+
+    /// Writes the config without checking `WHO_AM_I`, see [`crate::Lis3dh::new_unchecked`].
+    pub fn new_unchecked(mut bus: Bus, config: Config) -> Result<Self, Error<Bus::BusError>> {
+        write_config_bytes(&mut bus, Config::render_as_bytes())?;
+        Ok(Lis3dhBlocking { bus, config })
+    }
+
+    /// Reconfigures the lis3dh by re-writing the entire config, see [`crate::Lis3dh::reconfigure`].
+    pub fn reconfigure<NewConfig>(
+        self,
+        new_config: NewConfig,
+    ) -> Result<Lis3dhBlocking<Bus, NewConfig>, Error<Bus::BusError>>
+    where
+        NewConfig: ValidLis3dhConfig,
+    {
+        Lis3dhBlocking::new_unchecked(self.bus, new_config)
+    }
+
+    /// Consumes the driver and returns the owned bus, see [`crate::Lis3dh::release`].
+    pub fn release(self) -> Bus {
+        let Lis3dhBlocking { bus, .. } = self;
+        bus
+    }
+
+    pub fn read_who_am_i(&mut self) -> Result<u8, Error<Bus::BusError>> {
+        Ok(self.bus.read(ReadOnlyRegisterAddress::WhoAmI)?)
+    }
+
+    /// Reads `WHO_AM_I (0x0F)` as a [`DeviceId`], see [`crate::Lis3dh::read_device_id`].
+    pub fn read_device_id(&mut self) -> Result<DeviceId, Error<Bus::BusError>> {
+        Ok(DeviceId::from(self.read_who_am_i()?))
+    }
+}
+
+// Acceleration commands.
+// Only available when the output data rate isn't power-down, like the async driver.
+
+impl<Bus, Config> Lis3dhBlocking<Bus, Config>
+where
+    Bus: BlockingLis3dhBus,
+    Config: ValidLis3dhConfig,
+    Config::Odr: ctrl_reg1::odr::Active,
+{
+    /// Reads and returns the acceleration values from `OUT_X_L (0x28)` to `OUT_Z_U (0x2D)`
+    pub fn read_accel_bytes(&mut self) -> Result<[u8; 6], Error<Bus::BusError>> {
+        let mut result = [0; 6];
+        self.bus
+            .read_multiple(ReadOnlyRegisterAddress::OutXL, &mut result)?;
+        Ok(result)
+    }
+
+    /// Returns the resolution adjusted signed integer value from concatenated upper and lower bytes for each acceleration axis.
+    pub fn get_accel_vector(&mut self) -> Result<AccelerationVector, Error<Bus::BusError>> {
+        let accel_bytes = self.read_accel_bytes()?;
+        Ok(accel_bytes_into_vector::<Config>(accel_bytes))
+    }
+}
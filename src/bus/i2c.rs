@@ -0,0 +1,103 @@
+use embedded_hal::i2c::Operation as EmbeddedHalI2cOperation;
+use embedded_hal_async::i2c::I2c as EmbeddedHalAsyncI2c;
+
+use crate::bus::Lis3dhBus;
+use crate::registers::{ReadWriteRegisterAddress, RegisterAddress};
+
+// Setting the MSB of the sub-address auto increments the address in multiple read/write commands.
+const SUB_ADDRESS_AUTO_INCREMENT: u8 = 0b1_0000000;
+
+/// 7-bit I2C address of the lis3dh, selected by the level of the SDO/SA0 pin.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy)]
+#[repr(u8)]
+pub enum I2cAddress {
+    Sa0Low = 0x18,
+    Sa0High = 0x19,
+}
+
+impl From<bool> for I2cAddress {
+    /// Builds the address from the sampled SDO/SA0 pin level, `true` being high.
+    fn from(sa0_high: bool) -> Self {
+        if sa0_high {
+            I2cAddress::Sa0High
+        } else {
+            I2cAddress::Sa0Low
+        }
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Lis3dhAsyncI2c<I2c> {
+    pub i2c: I2c,
+    address: u8,
+}
+
+impl<I2c> Lis3dhAsyncI2c<I2c> {
+    pub fn new(i2c: I2c, address: impl Into<I2cAddress>) -> Self {
+        Lis3dhAsyncI2c {
+            i2c,
+            address: address.into() as u8,
+        }
+    }
+}
+
+impl<I2c, ErrI2c> Lis3dhBus for Lis3dhAsyncI2c<I2c>
+where
+    I2c: EmbeddedHalAsyncI2c<Error = ErrI2c>,
+{
+    type BusError = ErrI2c;
+
+    async fn write(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        value: u8,
+    ) -> Result<(), Self::BusError> {
+        let write_buf = [register_address as u8, value];
+        self.i2c.write(self.address, &write_buf).await?;
+        Ok(())
+    }
+
+    async unsafe fn write_multiple(
+        &mut self,
+        start_address: ReadWriteRegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::BusError> {
+        let sub_address_buf = [SUB_ADDRESS_AUTO_INCREMENT | start_address as u8];
+        // Adjacent write operations are sent as a single write, without a repeated start.
+        self.i2c
+            .transaction(
+                self.address,
+                &mut [
+                    EmbeddedHalI2cOperation::Write(&sub_address_buf),
+                    EmbeddedHalI2cOperation::Write(values),
+                ],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn read(
+        &mut self,
+        register_address: impl Into<RegisterAddress>,
+    ) -> Result<u8, Self::BusError> {
+        let sub_address_buf = [register_address.into().byte_address()];
+        let mut result_buf: [u8; 1] = [0u8];
+        self.i2c
+            .write_read(self.address, &sub_address_buf, &mut result_buf)
+            .await?;
+        Ok(result_buf[0])
+    }
+
+    async fn read_multiple(
+        &mut self,
+        start_address: impl Into<RegisterAddress>,
+        result: &mut [u8],
+    ) -> Result<(), Self::BusError> {
+        let sub_address_buf = [SUB_ADDRESS_AUTO_INCREMENT | start_address.into().byte_address()];
+        self.i2c
+            .write_read(self.address, &sub_address_buf, result)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use embedded_hal_async::delay::DelayNs;
+
+use crate::bus::Lis3dhBus;
+use crate::registers::{ReadWriteRegisterAddress, RegisterAddress};
+
+/// Backoff before the first retry. It doubles for every further retry.
+const INITIAL_BACKOFF_US: u32 = 500;
+
+/// Wraps any [`Lis3dhBus`] and retries each failed operation up to `retries` times, backing off with `delay` between attempts, e.g. to ride out transient NACKs on a noisy I2C line.
+///
+/// The error of the last attempt is returned once the retries are exhausted. The wrapper is itself a [`Lis3dhBus`], so it can be passed straight to [`crate::Lis3dh::new`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RetryBus<Bus, Delay> {
+    pub bus: Bus,
+    retries: u8,
+    delay: Delay,
+}
+
+impl<Bus, Delay> RetryBus<Bus, Delay> {
+    pub fn new(bus: Bus, retries: u8, delay: Delay) -> Self {
+        RetryBus {
+            bus,
+            retries,
+            delay,
+        }
+    }
+
+    /// Consumes the wrapper and returns the inner bus and delay.
+    pub fn release(self) -> (Bus, Delay) {
+        (self.bus, self.delay)
+    }
+}
+
+impl<Bus, Delay> RetryBus<Bus, Delay>
+where
+    Delay: DelayNs,
+{
+    /// Waits before retry number `retry` (starting at 0), returning false once the retries are exhausted.
+    async fn backoff(&mut self, retry: u8) -> bool {
+        if retry >= self.retries {
+            return false;
+        }
+        let backoff_us = INITIAL_BACKOFF_US.saturating_mul(1 << retry.min(16));
+        self.delay.delay_us(backoff_us).await;
+        true
+    }
+}
+
+impl<Bus, Delay> Lis3dhBus for RetryBus<Bus, Delay>
+where
+    Bus: Lis3dhBus,
+    Delay: DelayNs,
+{
+    type BusError = Bus::BusError;
+
+    async fn write(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        value: u8,
+    ) -> Result<(), Self::BusError> {
+        let mut retry = 0;
+        loop {
+            match self.bus.write(register_address, value).await {
+                Err(error) if !self.backoff(retry).await => return Err(error),
+                Err(_) => retry += 1,
+                Ok(()) => return Ok(()),
+            }
+        }
+    }
+
+    async unsafe fn write_multiple(
+        &mut self,
+        start_address: ReadWriteRegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::BusError> {
+        let mut retry = 0;
+        loop {
+            // SAFETY: The caller guarantees the registers in the broadcast are safe to write to, which holds for every attempt.
+            match unsafe { self.bus.write_multiple(start_address, values).await } {
+                Err(error) if !self.backoff(retry).await => return Err(error),
+                Err(_) => retry += 1,
+                Ok(()) => return Ok(()),
+            }
+        }
+    }
+
+    async fn read(
+        &mut self,
+        register_address: impl Into<RegisterAddress>,
+    ) -> Result<u8, Self::BusError> {
+        let register_address = register_address.into();
+        let mut retry = 0;
+        loop {
+            match self.bus.read(register_address).await {
+                Err(error) if !self.backoff(retry).await => return Err(error),
+                Err(_) => retry += 1,
+                Ok(value) => return Ok(value),
+            }
+        }
+    }
+
+    async fn read_multiple(
+        &mut self,
+        start_address: impl Into<RegisterAddress>,
+        result: &mut [u8],
+    ) -> Result<(), Self::BusError> {
+        let start_address = start_address.into();
+        let mut retry = 0;
+        loop {
+            match self.bus.read_multiple(start_address, result).await {
+                Err(error) if !self.backoff(retry).await => return Err(error),
+                Err(_) => retry += 1,
+                Ok(()) => return Ok(()),
+            }
+        }
+    }
+}
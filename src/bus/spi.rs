@@ -1,8 +1,13 @@
-use embedded_hal::{self, spi::Operation as EmbeddedHalSpiOperation};
-use embedded_hal_async::spi::SpiDevice as EmbeddedHalAsyncSpiDevice;
+use embedded_hal::{
+    self, digital::OutputPin, spi::Operation as EmbeddedHalSpiOperation,
+    spi::SpiDevice as EmbeddedHalSpiDevice,
+};
+use embedded_hal_async::spi::{
+    SpiBus as EmbeddedHalAsyncSpiBus, SpiDevice as EmbeddedHalAsyncSpiDevice,
+};
 
-use crate::bus::Lis3dhBus;
-use crate::registers::{ReadWriteRegisterAddress, RegisterAddress};
+use crate::bus::{BlockingLis3dhBus, Lis3dhBus};
+use crate::registers::{ctrl_reg4, ReadWriteRegisterAddress, RegisterAddress};
 
 pub enum Lis3dhOperation {
     SingleWrite = 0b00_000000,
@@ -85,3 +90,289 @@ where
         Ok(())
     }
 }
+
+/// `SIM` bit of `CTRL_REG4` selecting 3-wire SPI.
+const SIM_3_WIRE: u8 = (ctrl_reg4::sim::Variant::Spi3Wire as u8) << ctrl_reg4::sim::OFFSET;
+
+/// 3-wire (half-duplex) SPI bus, where the lis3dh shares a single SDI/SDO data line for both directions.
+///
+/// Reads clock out the command and then `0xFF` in a single bidirectional transfer, sampling the shared line while the lis3dh drives it. Wire MISO directly to the shared line and MOSI through a series resistor, so the lis3dh overrides the idle-high MOSI during the data phase.
+///
+/// The lis3dh powers up in 4-wire mode, where it answers on SDO rather than the shared line, so `SIM` must be set by a write (writes behave the same in either mode) before the first read. Call [`Lis3dhAsyncSpi3Wire::enable_3_wire_mode`] before [`crate::Lis3dh::new`], which reads `WHO_AM_I`. Every write covering `CTRL_REG4 (0x23)` keeps `SIM` set, so writing a config doesn't switch the lis3dh back to 4-wire.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Lis3dhAsyncSpi3Wire<Spi> {
+    pub spi: Spi,
+}
+
+impl<Spi, ErrSpi> Lis3dhAsyncSpi3Wire<Spi>
+where
+    Spi: EmbeddedHalAsyncSpiDevice<Error = ErrSpi>,
+{
+    /// Switches the lis3dh to 3-wire mode by setting `SIM` in `CTRL_REG4 (0x23)`. The rest of `CTRL_REG4` is cleared until the config is written.
+    pub async fn enable_3_wire_mode(&mut self) -> Result<(), ErrSpi> {
+        self.write(ReadWriteRegisterAddress::CtrlReg4, 0).await
+    }
+}
+
+impl<Spi, ErrSpi> Lis3dhBus for Lis3dhAsyncSpi3Wire<Spi>
+where
+    Spi: EmbeddedHalAsyncSpiDevice<Error = ErrSpi>,
+{
+    type BusError = ErrSpi;
+
+    async fn write(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        value: u8,
+    ) -> Result<(), Self::BusError> {
+        let value = match register_address {
+            ReadWriteRegisterAddress::CtrlReg4 => value | SIM_3_WIRE,
+            _ => value,
+        };
+        let write_buf = [
+            Lis3dhOperation::SingleWrite as u8 | register_address as u8,
+            value,
+        ];
+        self.spi
+            .transaction(&mut [EmbeddedHalSpiOperation::Write(&write_buf)])
+            .await?;
+        Ok(())
+    }
+
+    async unsafe fn write_multiple(
+        &mut self,
+        start_address: ReadWriteRegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::BusError> {
+        let address_buf = [Lis3dhOperation::MultipleWrite as u8 | start_address as u8];
+        let ctrl_reg4_index = (ctrl_reg4::ADDR as usize)
+            .checked_sub(start_address as usize)
+            .filter(|&index| index < values.len());
+
+        match ctrl_reg4_index {
+            Some(index) => {
+                let ctrl_reg4_buf = [values[index] | SIM_3_WIRE];
+                self.spi
+                    .transaction(&mut [
+                        EmbeddedHalSpiOperation::Write(&address_buf),
+                        EmbeddedHalSpiOperation::Write(&values[..index]),
+                        EmbeddedHalSpiOperation::Write(&ctrl_reg4_buf),
+                        EmbeddedHalSpiOperation::Write(&values[index + 1..]),
+                    ])
+                    .await?
+            }
+            None => {
+                self.spi
+                    .transaction(&mut [
+                        EmbeddedHalSpiOperation::Write(&address_buf),
+                        EmbeddedHalSpiOperation::Write(values),
+                    ])
+                    .await?
+            }
+        };
+        Ok(())
+    }
+
+    async fn read(
+        &mut self,
+        register_address: impl Into<RegisterAddress>,
+    ) -> Result<u8, Self::BusError> {
+        let register_address = register_address.into().byte_address();
+        let mut transfer_buf = [Lis3dhOperation::SingleRead as u8 | register_address, 0xFF];
+        self.spi
+            .transaction(&mut [EmbeddedHalSpiOperation::TransferInPlace(&mut transfer_buf)])
+            .await?;
+        Ok(transfer_buf[1])
+    }
+
+    async fn read_multiple(
+        &mut self,
+        start_address: impl Into<RegisterAddress>,
+        result: &mut [u8],
+    ) -> Result<(), Self::BusError> {
+        let start_address = start_address.into().byte_address();
+        let address_buf = [Lis3dhOperation::MultipleRead as u8 | start_address];
+        result.fill(0xFF);
+        self.spi
+            .transaction(&mut [
+                EmbeddedHalSpiOperation::Write(&address_buf),
+                EmbeddedHalSpiOperation::TransferInPlace(result),
+            ])
+            .await?;
+        Ok(())
+    }
+}
+
+/// Error of [`Lis3dhAsyncSpiBus`], which can fail on either the SPI bus or the chip select pin.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug)]
+pub enum SpiBusError<ErrSpi, ErrCs> {
+    Spi(ErrSpi),
+    ChipSelect(ErrCs),
+}
+
+/// SPI bus for users that manage chip select themselves, e.g. to share a raw `SpiBus` between devices without an `SpiDevice` wrapper.
+///
+/// `cs` is driven low (active) around each transaction and driven high again afterwards, even if the transaction failed. The bus is flushed before chip select is released.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Lis3dhAsyncSpiBus<Spi, Cs> {
+    pub spi: Spi,
+    pub cs: Cs,
+}
+
+impl<Spi, Cs, ErrSpi, ErrCs> Lis3dhAsyncSpiBus<Spi, Cs>
+where
+    Spi: EmbeddedHalAsyncSpiBus<Error = ErrSpi>,
+    Cs: OutputPin<Error = ErrCs>,
+{
+    fn select(&mut self) -> Result<(), SpiBusError<ErrSpi, ErrCs>> {
+        self.cs.set_low().map_err(SpiBusError::ChipSelect)
+    }
+
+    /// Releases chip select, then returns the result of the transaction.
+    fn deselect<T>(&mut self, result: Result<T, ErrSpi>) -> Result<T, SpiBusError<ErrSpi, ErrCs>> {
+        self.cs.set_high().map_err(SpiBusError::ChipSelect)?;
+        result.map_err(SpiBusError::Spi)
+    }
+}
+
+impl<Spi, Cs, ErrSpi, ErrCs> Lis3dhBus for Lis3dhAsyncSpiBus<Spi, Cs>
+where
+    Spi: EmbeddedHalAsyncSpiBus<Error = ErrSpi>,
+    Cs: OutputPin<Error = ErrCs>,
+{
+    type BusError = SpiBusError<ErrSpi, ErrCs>;
+
+    async fn write(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        value: u8,
+    ) -> Result<(), Self::BusError> {
+        let write_buf = [
+            Lis3dhOperation::SingleWrite as u8 | register_address as u8,
+            value,
+        ];
+        self.select()?;
+        let result = async {
+            self.spi.write(&write_buf).await?;
+            self.spi.flush().await
+        }
+        .await;
+        self.deselect(result)
+    }
+
+    async unsafe fn write_multiple(
+        &mut self,
+        start_address: ReadWriteRegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::BusError> {
+        let address_buf = [Lis3dhOperation::MultipleWrite as u8 | start_address as u8];
+        self.select()?;
+        let result = async {
+            self.spi.write(&address_buf).await?;
+            self.spi.write(values).await?;
+            self.spi.flush().await
+        }
+        .await;
+        self.deselect(result)
+    }
+
+    async fn read(
+        &mut self,
+        register_address: impl Into<RegisterAddress>,
+    ) -> Result<u8, Self::BusError> {
+        let register_address = register_address.into().byte_address();
+        let address_buf: [u8; 1] = [Lis3dhOperation::SingleRead as u8 | register_address];
+        let mut result_buf: [u8; 1] = [0u8];
+        self.select()?;
+        let result = async {
+            self.spi.write(&address_buf).await?;
+            self.spi.read(&mut result_buf).await?;
+            self.spi.flush().await
+        }
+        .await;
+        self.deselect(result)?;
+        Ok(result_buf[0])
+    }
+
+    async fn read_multiple(
+        &mut self,
+        start_address: impl Into<RegisterAddress>,
+        result: &mut [u8],
+    ) -> Result<(), Self::BusError> {
+        let start_address = start_address.into().byte_address();
+        let address_buf = [Lis3dhOperation::MultipleRead as u8 | start_address];
+        self.select()?;
+        let transaction_result = async {
+            self.spi.write(&address_buf).await?;
+            self.spi.read(result).await?;
+            self.spi.flush().await
+        }
+        .await;
+        self.deselect(transaction_result)
+    }
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Lis3dhBlockingSpi<Spi> {
+    pub spi: Spi,
+}
+
+impl<Spi, ErrSpi> BlockingLis3dhBus for Lis3dhBlockingSpi<Spi>
+where
+    Spi: EmbeddedHalSpiDevice<Error = ErrSpi>,
+{
+    type BusError = ErrSpi;
+
+    fn write(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        value: u8,
+    ) -> Result<(), Self::BusError> {
+        let write_buf = [
+            Lis3dhOperation::SingleWrite as u8 | register_address as u8,
+            value,
+        ];
+        self.spi
+            .transaction(&mut [EmbeddedHalSpiOperation::Write(&write_buf)])?;
+        Ok(())
+    }
+
+    unsafe fn write_multiple(
+        &mut self,
+        start_address: ReadWriteRegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::BusError> {
+        let address_buf = [Lis3dhOperation::MultipleWrite as u8 | start_address as u8];
+        self.spi.transaction(&mut [
+            EmbeddedHalSpiOperation::Write(&address_buf),
+            EmbeddedHalSpiOperation::Write(values),
+        ])?;
+        Ok(())
+    }
+
+    fn read(&mut self, register_address: impl Into<RegisterAddress>) -> Result<u8, Self::BusError> {
+        let register_address = register_address.into().byte_address();
+        let address_buf: [u8; 1] = [Lis3dhOperation::SingleRead as u8 | register_address];
+        let mut result_buf: [u8; 1] = [0u8];
+        self.spi.transaction(&mut [
+            EmbeddedHalSpiOperation::Write(&address_buf),
+            EmbeddedHalSpiOperation::Read(&mut result_buf),
+        ])?;
+        Ok(result_buf[0])
+    }
+
+    fn read_multiple(
+        &mut self,
+        start_address: impl Into<RegisterAddress>,
+        result: &mut [u8],
+    ) -> Result<(), Self::BusError> {
+        let start_address = start_address.into().byte_address();
+        let address_buf = [Lis3dhOperation::MultipleRead as u8 | start_address];
+        self.spi.transaction(&mut [
+            EmbeddedHalSpiOperation::Write(&address_buf),
+            EmbeddedHalSpiOperation::Read(result),
+        ])?;
+        Ok(())
+    }
+}
@@ -1,21 +1,87 @@
+pub mod presets;
+
 use crate::properties::*;
 use crate::registers::*;
 
 // Necessary functionality (for Bombus) can be achieved by only configuring ctrl_reg1 and ctrl_reg4.
 // TODO: Add all additional functionality to Config.
-pub struct Config<Odr, LpEn, AxisEnable, Fs, Hr>
+pub struct Config<Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth>
 where
     Odr: ctrl_reg1::odr::State + Entitled<LpEn>,
     LpEn: ctrl_reg1::lp_en::State,
-    AxisEnable: ctrl_reg1::axis_enable::State,
+    AxisEnable: ctrl_reg1::axis_enable::State + Entitled<Odr>,
     Fs: ctrl_reg4::fs::State,
     Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+    Bdu: ctrl_reg4::bdu::State,
+    Ble: ctrl_reg4::ble::State + Entitled<Hr>,
+    AdcEn: temp_cfg_reg::adc_en::State,
+    TempEn: temp_cfg_reg::temp_en::State + Entitled<AdcEn>,
+    FifoEn: ctrl_reg5::fifo_en::State,
+    Fm: fifo_ctrl_reg::fm::State + Entitled<FifoEn>,
+    Fth: fifo_ctrl_reg::fth::State,
 {
     pub data_rate: Odr,
     pub power_mode: LpEn,
     pub axis_enable: AxisEnable,
     pub full_scale: Fs,
     pub resolution_mode: Hr,
+    pub block_data_update: Bdu,
+    pub endianness: Ble,
+    pub adc_enable: AdcEn,
+    pub temperature_enable: TempEn,
+    pub fifo_enable: FifoEn,
+    pub fifo_mode: Fm,
+    pub fifo_watermark: Fth,
+}
+
+impl<Odr, LpEn, AxisEnable, Fs, Hr>
+    Config<
+        Odr,
+        LpEn,
+        AxisEnable,
+        Fs,
+        Hr,
+        ctrl_reg4::bdu::ContinuousDataUpdate,
+        ctrl_reg4::ble::LittleEndian,
+        temp_cfg_reg::adc_en::AdcDisabled,
+        temp_cfg_reg::temp_en::TempDisabled,
+        ctrl_reg5::fifo_en::FifoDisabled,
+        fifo_ctrl_reg::fm::Bypass,
+        fifo_ctrl_reg::fth::Watermark<0>,
+    >
+where
+    Odr: ctrl_reg1::odr::State + Entitled<LpEn>,
+    LpEn: ctrl_reg1::lp_en::State,
+    AxisEnable: ctrl_reg1::axis_enable::State + Entitled<Odr>,
+    Fs: ctrl_reg4::fs::State,
+    Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+{
+    /// Builds a config from an [`operating_mode::OperatingMode`], the enabled axes and the full scale. Everything else is left at its power-on default, like [`presets::DEFAULT_100HZ_2G`].
+    pub fn from_operating_mode(
+        operating_mode: operating_mode::OperatingMode<Odr, LpEn, Hr>,
+        axis_enable: AxisEnable,
+        full_scale: Fs,
+    ) -> Self {
+        let operating_mode::OperatingMode {
+            data_rate,
+            power_mode,
+            resolution_mode,
+        } = operating_mode;
+        Config {
+            data_rate,
+            power_mode,
+            axis_enable,
+            full_scale,
+            resolution_mode,
+            block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+            endianness: ctrl_reg4::ble::LittleEndian,
+            adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+            temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+            fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+            fifo_mode: fifo_ctrl_reg::fm::Bypass,
+            fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+        }
+    }
 }
 
 /// The register values represented by some [`ValidLis3dhConfig`].
@@ -23,10 +89,52 @@ pub struct ConfigAsBytes {
     pub(crate) ctrl_reg0: u8,
     pub(crate) temp_cfg_reg: u8,
     pub(crate) ctrl_reg1: u8,
+    pub(crate) ctrl_reg2: u8,
+    pub(crate) ctrl_reg3: u8,
     pub(crate) ctrl_reg4: u8,
+    pub(crate) ctrl_reg5: u8,
+    pub(crate) ctrl_reg6: u8,
+    pub(crate) fifo_ctrl_reg: u8,
     // More registers to come...
 }
 
+impl ConfigAsBytes {
+    /// Returns the rendered register values as `(address, value)` pairs in the order they are written to the lis3dh.
+    pub fn register_values(&self) -> [(u8, u8); 9] {
+        [
+            (ctrl_reg0::ADDR, self.ctrl_reg0),
+            (temp_cfg_reg::ADDR, self.temp_cfg_reg),
+            (ctrl_reg1::ADDR, self.ctrl_reg1),
+            (ctrl_reg2::ADDR, self.ctrl_reg2),
+            (ctrl_reg3::ADDR, self.ctrl_reg3),
+            (ctrl_reg4::ADDR, self.ctrl_reg4),
+            (ctrl_reg5::ADDR, self.ctrl_reg5),
+            (ctrl_reg6::ADDR, self.ctrl_reg6),
+            (fifo_ctrl_reg::ADDR, self.fifo_ctrl_reg),
+        ]
+    }
+
+    /// Returns the values of `CTRL_REG0 (0x1E)` to `CTRL_REG6 (0x25)`, which are written to the lis3dh in a single burst.
+    pub(crate) const fn ctrl_reg0_to_ctrl_reg6(&self) -> [u8; 8] {
+        [
+            self.ctrl_reg0,
+            self.temp_cfg_reg,
+            self.ctrl_reg1,
+            self.ctrl_reg2,
+            self.ctrl_reg3,
+            self.ctrl_reg4,
+            self.ctrl_reg5,
+            self.ctrl_reg6,
+        ]
+    }
+}
+
+/// Reset values of `CTRL_REG0 (0x1E)` to `CTRL_REG6 (0x25)` in address order, rendered from the `Default` type-state of every field, e.g. to write the whole control block in one burst after a `BOOT`.
+///
+/// `TEMP_CFG_REG (0x1F)` sits between `CTRL_REG0` and `CTRL_REG1`, so the block is 8 bytes rather than one per `CTRL_REG`. [`crate::Lis3dh::reset_to_defaults`] writes it.
+pub const DEFAULT_CONFIG_BYTES: [u8; 8] =
+    presets::PowerOnDefault::render_bytes().ctrl_reg0_to_ctrl_reg6();
+
 mod sealed {
     pub trait Sealed {}
 }
@@ -36,36 +144,62 @@ pub trait ValidLis3dhConfig: sealed::Sealed {
     // Type-states corresponding to lis3dh Config and entitlement check.
     type Odr: ctrl_reg1::odr::State + Entitled<Self::LpEn>;
     type LpEn: ctrl_reg1::lp_en::State;
-    type AxisEnable: ctrl_reg1::axis_enable::State;
+    type AxisEnable: ctrl_reg1::axis_enable::State + Entitled<Self::Odr>;
     type Fs: ctrl_reg4::fs::State;
     type Hr: ctrl_reg4::hr::State + Entitled<Self::LpEn>;
+    type Bdu: ctrl_reg4::bdu::State;
+    type Ble: ctrl_reg4::ble::State + Entitled<Self::Hr>;
+    type AdcEn: temp_cfg_reg::adc_en::State;
+    type TempEn: temp_cfg_reg::temp_en::State + Entitled<Self::AdcEn>;
+    type FifoEn: ctrl_reg5::fifo_en::State;
+    type Fm: fifo_ctrl_reg::fm::State + Entitled<Self::FifoEn>;
+    type Fth: fifo_ctrl_reg::fth::State;
 
     // Properties corresponding to lis3dh Config.
     type Resolution: resolution::Property;
     type GravityCoefficient: gravity_coefficient::Property;
+    type OdrHz: odr_hz::Property;
+    type ThresholdCoefficient: threshold_coefficient::Property;
+    type OperatingMode: operating_mode::Property;
 
     /// Render some [`ValidLis3dhConfig`] to bytes.
     fn render_as_bytes() -> ConfigAsBytes;
 }
 
-impl<Odr, LpEn, AxisEnable, Fs, Hr> sealed::Sealed for Config<Odr, LpEn, AxisEnable, Fs, Hr>
+impl<Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth> sealed::Sealed
+    for Config<Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth>
 where
     Odr: ctrl_reg1::odr::State + Entitled<LpEn>,
     LpEn: ctrl_reg1::lp_en::State,
-    AxisEnable: ctrl_reg1::axis_enable::State,
+    AxisEnable: ctrl_reg1::axis_enable::State + Entitled<Odr>,
     Fs: ctrl_reg4::fs::State,
     Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+    Bdu: ctrl_reg4::bdu::State,
+    Ble: ctrl_reg4::ble::State + Entitled<Hr>,
+    AdcEn: temp_cfg_reg::adc_en::State,
+    TempEn: temp_cfg_reg::temp_en::State + Entitled<AdcEn>,
+    FifoEn: ctrl_reg5::fifo_en::State,
+    Fm: fifo_ctrl_reg::fm::State + Entitled<FifoEn>,
+    Fth: fifo_ctrl_reg::fth::State,
 {
 }
 
 // TODO: Create helper traits per register to improve readability and reduce number of generic parameters.
-impl<Odr, LpEn, AxisEnable, Fs, Hr> ValidLis3dhConfig for Config<Odr, LpEn, AxisEnable, Fs, Hr>
+impl<Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth> ValidLis3dhConfig
+    for Config<Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth>
 where
     Odr: ctrl_reg1::odr::State + Entitled<LpEn>,
     LpEn: ctrl_reg1::lp_en::State,
-    AxisEnable: ctrl_reg1::axis_enable::State,
+    AxisEnable: ctrl_reg1::axis_enable::State + Entitled<Odr>,
     Fs: ctrl_reg4::fs::State,
     Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+    Bdu: ctrl_reg4::bdu::State,
+    Ble: ctrl_reg4::ble::State + Entitled<Hr>,
+    AdcEn: temp_cfg_reg::adc_en::State,
+    TempEn: temp_cfg_reg::temp_en::State + Entitled<AdcEn>,
+    FifoEn: ctrl_reg5::fifo_en::State,
+    Fm: fifo_ctrl_reg::fm::State + Entitled<FifoEn>,
+    Fth: fifo_ctrl_reg::fth::State,
 {
     // Type-States
     type Odr = Odr;
@@ -73,30 +207,339 @@ where
     type AxisEnable = AxisEnable;
     type Fs = Fs;
     type Hr = Hr;
+    type Bdu = Bdu;
+    type Ble = Ble;
+    type AdcEn = AdcEn;
+    type TempEn = TempEn;
+    type FifoEn = FifoEn;
+    type Fm = Fm;
+    type Fth = Fth;
 
     // Resulting Properties:
     type Resolution = resolution::Resolution<Self::LpEn, Self::Hr>;
     type GravityCoefficient = gravity_coefficient::GravityCoefficient<Self::Fs, Self::Resolution>;
+    type OdrHz = odr_hz::OdrHz<Self::Odr, Self::LpEn>;
+    type ThresholdCoefficient = threshold_coefficient::ThresholdCoefficient<Self::Fs>;
+    type OperatingMode = operating_mode::OperatingMode<Self::Odr, Self::LpEn, Self::Hr>;
 
     fn render_as_bytes() -> ConfigAsBytes {
+        Self::render_bytes()
+    }
+}
+
+impl<Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth>
+    Config<Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth>
+where
+    Odr: ctrl_reg1::odr::State + Entitled<LpEn>,
+    LpEn: ctrl_reg1::lp_en::State,
+    AxisEnable: ctrl_reg1::axis_enable::State + Entitled<Odr>,
+    Fs: ctrl_reg4::fs::State,
+    Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+    Bdu: ctrl_reg4::bdu::State,
+    Ble: ctrl_reg4::ble::State + Entitled<Hr>,
+    AdcEn: temp_cfg_reg::adc_en::State,
+    TempEn: temp_cfg_reg::temp_en::State + Entitled<AdcEn>,
+    FifoEn: ctrl_reg5::fifo_en::State,
+    Fm: fifo_ctrl_reg::fm::State + Entitled<FifoEn>,
+    Fth: fifo_ctrl_reg::fth::State,
+{
+    /// Renders the register values of this config like [`ValidLis3dhConfig::render_as_bytes`], but usable in constants such as [`DEFAULT_CONFIG_BYTES`].
+    pub(crate) const fn render_bytes() -> ConfigAsBytes {
         ConfigAsBytes {
             ctrl_reg0: ctrl_reg0::render_hardware_state::<
                 ctrl_reg0::sdo_pu_disc::Default,
                 ctrl_reg0::must_set_bits::Default,
             >(),
-            temp_cfg_reg: temp_cfg_reg::render_hardware_state::<
-                temp_cfg_reg::adc_en::Default,
-                temp_cfg_reg::temp_en::Default,
-            >(),
+            temp_cfg_reg: temp_cfg_reg::render_hardware_state::<AdcEn, TempEn>(),
             ctrl_reg1: ctrl_reg1::render_hardware_state::<Odr, LpEn, AxisEnable>(),
+            ctrl_reg2: ctrl_reg2::render_hardware_state::<
+                ctrl_reg2::hpm::Default,
+                ctrl_reg2::hpcf::Default,
+                ctrl_reg2::fds::Default,
+                ctrl_reg2::hpclick::Default,
+                ctrl_reg2::hp_ia2::Default,
+                ctrl_reg2::hp_ia1::Default,
+            >(),
+            ctrl_reg3: ctrl_reg3::render_hardware_state::<
+                ctrl_reg3::i1_click::Default,
+                ctrl_reg3::i1_ia1::Default,
+                ctrl_reg3::i1_ia2::Default,
+                ctrl_reg3::i1_zyxda::Default,
+                ctrl_reg3::i1_321da::Default,
+                ctrl_reg3::i1_wtm::Default,
+                ctrl_reg3::i1_overrun::Default,
+            >(),
             ctrl_reg4: ctrl_reg4::render_hardware_state::<
-                ctrl_reg4::bdu::Default,
-                ctrl_reg4::ble::Default,
+                Bdu,
+                Ble,
                 Fs,
                 Hr,
                 ctrl_reg4::st::Default,
                 ctrl_reg4::sim::Default,
             >(),
+            ctrl_reg5: ctrl_reg5::render_hardware_state::<
+                ctrl_reg5::boot::Default,
+                FifoEn,
+                ctrl_reg5::lir_int1::Default,
+                ctrl_reg5::d4d_int1::Default,
+                ctrl_reg5::lir_int2::Default,
+                ctrl_reg5::d4d_int2::Default,
+            >(),
+            ctrl_reg6: ctrl_reg6::render_hardware_state::<
+                ctrl_reg6::i2_click::Default,
+                ctrl_reg6::i2_ia1::Default,
+                ctrl_reg6::i2_ia2::Default,
+                ctrl_reg6::i2_boot::Default,
+                ctrl_reg6::i2_act::Default,
+                ctrl_reg6::int_polarity::Default,
+            >(),
+            fifo_ctrl_reg: fifo_ctrl_reg::render_hardware_state::<
+                Fm,
+                fifo_ctrl_reg::tr::Default,
+                Fth,
+            >(),
+        }
+    }
+}
+
+/// Runtime counterpart of [`Config`] for configurations that are only known at runtime, e.g. a descriptor received over a network or BLE link.
+///
+/// Each field holds the raw bit-field value of the matching [`Config`] field. A [`Config`] is checked by the compiler through its type-states and entitlements, whereas a `DynamicConfig` trades those compile-time guarantees for runtime flexibility: [`DynamicConfig::validate`] enforces the same rules when the descriptor is received.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy)]
+pub struct DynamicConfig {
+    /// Raw [`ctrl_reg1::odr`] value.
+    pub data_rate: u8,
+    /// Raw [`ctrl_reg1::lp_en`] value.
+    pub power_mode: u8,
+    /// Raw [`ctrl_reg1::axis_enable`] value.
+    pub axis_enable: u8,
+    /// Raw [`ctrl_reg4::fs`] value.
+    pub full_scale: u8,
+    /// Raw [`ctrl_reg4::hr`] value.
+    pub resolution_mode: u8,
+    /// Raw [`ctrl_reg4::bdu`] value.
+    pub block_data_update: u8,
+    /// Raw [`ctrl_reg4::ble`] value.
+    pub endianness: u8,
+    /// Raw [`temp_cfg_reg::adc_en`] value.
+    pub adc_enable: u8,
+    /// Raw [`temp_cfg_reg::temp_en`] value.
+    pub temperature_enable: u8,
+    /// Raw [`ctrl_reg5::fifo_en`] value.
+    pub fifo_enable: u8,
+    /// Raw [`fifo_ctrl_reg::fm`] value.
+    pub fifo_mode: u8,
+    /// Raw [`fifo_ctrl_reg::fth`] value.
+    pub fifo_watermark: u8,
+}
+
+/// Reasons a [`DynamicConfig`] can fail validation.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConfigError {
+    /// The raw value doesn't correspond to any [`ctrl_reg1::odr::Variant`].
+    InvalidDataRate(u8),
+    /// The raw value doesn't correspond to any [`ctrl_reg1::lp_en::Variant`].
+    InvalidPowerMode(u8),
+    /// The raw value doesn't correspond to any [`ctrl_reg1::axis_enable::Variant`].
+    InvalidAxisEnable(u8),
+    /// The raw value doesn't correspond to any [`ctrl_reg4::fs::Variant`].
+    InvalidFullScale(u8),
+    /// The raw value doesn't correspond to any [`ctrl_reg4::hr::Variant`].
+    InvalidResolutionMode(u8),
+    /// The raw value doesn't correspond to any [`ctrl_reg4::bdu::Variant`].
+    InvalidBlockDataUpdate(u8),
+    /// The raw value doesn't correspond to any [`ctrl_reg4::ble::Variant`].
+    InvalidEndianness(u8),
+    /// The raw value doesn't correspond to any [`temp_cfg_reg::adc_en::Variant`].
+    InvalidAdcEnable(u8),
+    /// The raw value doesn't correspond to any [`temp_cfg_reg::temp_en::Variant`].
+    InvalidTemperatureEnable(u8),
+    /// The raw value doesn't correspond to any [`ctrl_reg5::fifo_en::Variant`].
+    InvalidFifoEnable(u8),
+    /// The raw value doesn't correspond to any [`fifo_ctrl_reg::fm::Variant`].
+    InvalidFifoMode(u8),
+    /// The raw value doesn't fit in [`fifo_ctrl_reg::fth`].
+    InvalidFifoWatermark(u8),
+    /// The data rate is not entitled to the power mode, e.g. 1.6 kHz in normal power mode.
+    DataRateNotEntitled,
+    /// Every axis is disabled while the data rate isn't power-down.
+    AxisEnableNotEntitled,
+    /// The resolution mode is not entitled to the power mode, i.e. high-resolution in low power mode.
+    ResolutionModeNotEntitled,
+    /// The endianness is not entitled to the resolution mode, i.e. big endian without high-resolution.
+    EndiannessNotEntitled,
+    /// The temperature sensor is enabled without the ADC.
+    TemperatureEnableNotEntitled,
+    /// A FIFO mode other than bypass is selected without enabling the FIFO.
+    FifoModeNotEntitled,
+    /// Applying the config would contradict the type-states of the `Lis3dh` it is applied to: the output data rate is power-down in one and active in the other, or the power mode, resolution mode, full scale or endianness differ.
+    TypeStateMismatch,
+}
+
+impl DynamicConfig {
+    /// Checks that every field holds a valid raw value and that the fields respect the same entitlements as [`Config`].
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        use ctrl_reg1::{axis_enable, lp_en, odr};
+        use ctrl_reg4::{bdu, ble, fs, hr};
+        use ctrl_reg5::fifo_en;
+        use fifo_ctrl_reg::{fm, fth};
+        use temp_cfg_reg::{adc_en, temp_en};
+
+        if self.data_rate > odr::Variant::F1344Hz as u8 {
+            return Err(ConfigError::InvalidDataRate(self.data_rate));
+        }
+        if self.power_mode > lp_en::Variant::LowPowerMode as u8 {
+            return Err(ConfigError::InvalidPowerMode(self.power_mode));
+        }
+        if self.axis_enable > axis_enable::Variant::XYZEnabled as u8 {
+            return Err(ConfigError::InvalidAxisEnable(self.axis_enable));
+        }
+        if self.full_scale > fs::Variant::S16G as u8 {
+            return Err(ConfigError::InvalidFullScale(self.full_scale));
+        }
+        if self.resolution_mode > hr::Variant::HighResolution as u8 {
+            return Err(ConfigError::InvalidResolutionMode(self.resolution_mode));
+        }
+        if self.block_data_update > bdu::Variant::BlockDataUpdate as u8 {
+            return Err(ConfigError::InvalidBlockDataUpdate(self.block_data_update));
+        }
+        if self.endianness > ble::Variant::BigEndian as u8 {
+            return Err(ConfigError::InvalidEndianness(self.endianness));
+        }
+        if self.adc_enable > adc_en::Variant::AdcEnabled as u8 {
+            return Err(ConfigError::InvalidAdcEnable(self.adc_enable));
+        }
+        if self.temperature_enable > temp_en::Variant::TempEnabled as u8 {
+            return Err(ConfigError::InvalidTemperatureEnable(
+                self.temperature_enable,
+            ));
+        }
+        if self.fifo_enable > fifo_en::Variant::FifoEnabled as u8 {
+            return Err(ConfigError::InvalidFifoEnable(self.fifo_enable));
+        }
+        if self.fifo_mode > fm::Variant::StreamToFifo as u8 {
+            return Err(ConfigError::InvalidFifoMode(self.fifo_mode));
+        }
+        if self.fifo_watermark >= 1 << fth::WIDTH {
+            return Err(ConfigError::InvalidFifoWatermark(self.fifo_watermark));
+        }
+
+        let low_power_mode = self.power_mode == lp_en::Variant::LowPowerMode as u8;
+
+        // Raw value 0b1001 is valid in both power modes (1.344 kHz in normal power mode, 5.376 kHz in low power mode), so only 1.6 kHz needs checking.
+        if self.data_rate == odr::Variant::F1600Hz as u8 && !low_power_mode {
+            return Err(ConfigError::DataRateNotEntitled);
+        }
+        if self.axis_enable == axis_enable::Variant::XYZDisabled as u8
+            && self.data_rate != odr::Variant::PowerDown as u8
+        {
+            return Err(ConfigError::AxisEnableNotEntitled);
+        }
+        if self.resolution_mode == hr::Variant::HighResolution as u8 && low_power_mode {
+            return Err(ConfigError::ResolutionModeNotEntitled);
+        }
+        if self.endianness == ble::Variant::BigEndian as u8
+            && self.resolution_mode != hr::Variant::HighResolution as u8
+        {
+            return Err(ConfigError::EndiannessNotEntitled);
+        }
+        if self.temperature_enable == temp_en::Variant::TempEnabled as u8
+            && self.adc_enable != adc_en::Variant::AdcEnabled as u8
+        {
+            return Err(ConfigError::TemperatureEnableNotEntitled);
+        }
+        if self.fifo_mode != fm::Variant::Bypass as u8
+            && self.fifo_enable != fifo_en::Variant::FifoEnabled as u8
+        {
+            return Err(ConfigError::FifoModeNotEntitled);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the fields the driver relies on match the type-states of `Config`, see [`DynamicConfig::apply`].
+    fn check_type_states<Config: ValidLis3dhConfig>(&self) -> Result<(), ConfigError> {
+        use ctrl_reg1::{lp_en, odr};
+        use ctrl_reg4::{ble, fs, hr};
+
+        let power_down = odr::Variant::PowerDown as u8;
+        let matches = (self.data_rate == power_down)
+            == (<Config::Odr as odr::State>::VARIANT as u8 == power_down)
+            && self.power_mode == <Config::LpEn as lp_en::State>::VARIANT as u8
+            && self.resolution_mode == <Config::Hr as hr::State>::VARIANT as u8
+            && self.full_scale == <Config::Fs as fs::State>::VARIANT as u8
+            && self.endianness == <Config::Ble as ble::State>::VARIANT as u8;
+        if !matches {
+            return Err(ConfigError::TypeStateMismatch);
         }
+        Ok(())
+    }
+
+    /// Render the [`DynamicConfig`] to bytes. Fields not covered by [`DynamicConfig`] take their default values.
+    fn render_as_bytes(&self) -> ConfigAsBytes {
+        let ConfigAsBytes {
+            ctrl_reg0,
+            ctrl_reg2,
+            ctrl_reg3,
+            ctrl_reg4,
+            ctrl_reg6,
+            fifo_ctrl_reg,
+            ..
+        } = <Config<
+            ctrl_reg1::odr::Default,
+            ctrl_reg1::lp_en::Default,
+            ctrl_reg1::axis_enable::Default,
+            ctrl_reg4::fs::Default,
+            ctrl_reg4::hr::Default,
+            ctrl_reg4::bdu::Default,
+            ctrl_reg4::ble::Default,
+            temp_cfg_reg::adc_en::Default,
+            temp_cfg_reg::temp_en::Default,
+            ctrl_reg5::fifo_en::Default,
+            fifo_ctrl_reg::fm::Default,
+            fifo_ctrl_reg::fth::Default,
+        > as ValidLis3dhConfig>::render_as_bytes();
+
+        ConfigAsBytes {
+            ctrl_reg0,
+            temp_cfg_reg: (self.adc_enable << temp_cfg_reg::adc_en::OFFSET)
+                | (self.temperature_enable << temp_cfg_reg::temp_en::OFFSET),
+            ctrl_reg1: (self.data_rate << ctrl_reg1::odr::OFFSET)
+                | (self.power_mode << ctrl_reg1::lp_en::OFFSET)
+                | (self.axis_enable << ctrl_reg1::axis_enable::OFFSET),
+            ctrl_reg2,
+            ctrl_reg3,
+            // The default `bdu`, `ble`, `fs` and `hr` raw values are all 0, so the runtime values can be OR'd in.
+            ctrl_reg4: ctrl_reg4
+                | (self.block_data_update << ctrl_reg4::bdu::OFFSET)
+                | (self.endianness << ctrl_reg4::ble::OFFSET)
+                | (self.full_scale << ctrl_reg4::fs::OFFSET)
+                | (self.resolution_mode << ctrl_reg4::hr::OFFSET),
+            ctrl_reg5: self.fifo_enable << ctrl_reg5::fifo_en::OFFSET,
+            ctrl_reg6,
+            // The default `fm` and `fth` raw values are both 0, so the runtime values can be OR'd in.
+            fifo_ctrl_reg: fifo_ctrl_reg
+                | (self.fifo_mode << fifo_ctrl_reg::fm::OFFSET)
+                | (self.fifo_watermark << fifo_ctrl_reg::fth::OFFSET),
+        }
+    }
+
+    /// Validates the [`DynamicConfig`] and, if valid and consistent with the `Config` type-state of `lis3dh`, writes it to the lis3dh. Nothing is written if either check fails.
+    ///
+    /// The driver keeps decoding samples and guarding commands by its `Config`, so the config is rejected with [`ConfigError::TypeStateMismatch`] unless it matches `Config` in everything those depend on: whether the output data rate is power-down, the power mode, resolution mode, full scale and endianness. The other fields, such as the exact active output data rate or the FIFO setup, can change freely.
+    pub async fn apply<Bus, Config>(
+        &self,
+        lis3dh: &mut crate::Lis3dh<Bus, Config>,
+    ) -> Result<(), crate::Error<Bus::BusError>>
+    where
+        Bus: crate::bus::Lis3dhBus,
+        Config: ValidLis3dhConfig,
+    {
+        self.validate().map_err(crate::Error::InvalidConfig)?;
+        self.check_type_states::<Config>()
+            .map_err(crate::Error::InvalidConfig)?;
+        crate::write_config_bytes(&mut lis3dh.bus, self.render_as_bytes()).await
     }
 }
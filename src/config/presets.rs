@@ -0,0 +1,102 @@
+//! Ready-made [`Config`]s for common use cases.
+//!
+//! Every field of a [`Config`] is a zero-sized type-state, so each preset is a zero-sized const. Pass one straight to [`crate::Lis3dh::new`], or name its type alias when the driver is stored in a struct.
+
+use super::Config;
+use crate::registers::{ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg};
+
+/// Type of [`POWER_ON_DEFAULT`], made of the `Default` type-state of every field.
+pub type PowerOnDefault = Config<
+    ctrl_reg1::odr::Default,
+    ctrl_reg1::lp_en::Default,
+    ctrl_reg1::axis_enable::Default,
+    ctrl_reg4::fs::Default,
+    ctrl_reg4::hr::Default,
+    ctrl_reg4::bdu::Default,
+    ctrl_reg4::ble::Default,
+    temp_cfg_reg::adc_en::Default,
+    temp_cfg_reg::temp_en::Default,
+    ctrl_reg5::fifo_en::Default,
+    fifo_ctrl_reg::fm::Default,
+    fifo_ctrl_reg::fth::Default,
+>;
+
+/// The lis3dh's power-on state: powered down with all axes enabled, ±2 g and 10-bit normal mode. See [`crate::Lis3dh::reset_to_defaults`].
+pub const POWER_ON_DEFAULT: PowerOnDefault = Config {
+    data_rate: ctrl_reg1::odr::PowerDown,
+    power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+    axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+    full_scale: ctrl_reg4::fs::S2G,
+    resolution_mode: ctrl_reg4::hr::NormalResolution,
+    block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+    endianness: ctrl_reg4::ble::LittleEndian,
+    adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+    temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+    fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+    fifo_mode: fifo_ctrl_reg::fm::Bypass,
+    fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+};
+
+/// Type of [`DEFAULT_100HZ_2G`].
+pub type Default100Hz2G = Config<
+    ctrl_reg1::odr::F100Hz,
+    ctrl_reg1::lp_en::NormalPowerMode,
+    ctrl_reg1::axis_enable::XYZEnabled,
+    ctrl_reg4::fs::S2G,
+    ctrl_reg4::hr::NormalResolution,
+    ctrl_reg4::bdu::ContinuousDataUpdate,
+    ctrl_reg4::ble::LittleEndian,
+    temp_cfg_reg::adc_en::AdcDisabled,
+    temp_cfg_reg::temp_en::TempDisabled,
+    ctrl_reg5::fifo_en::FifoDisabled,
+    fifo_ctrl_reg::fm::Bypass,
+    fifo_ctrl_reg::fth::Watermark<0>,
+>;
+
+/// 100 Hz, ±2 g, 10-bit normal mode with all axes enabled. Everything else is left at its power-on default.
+pub const DEFAULT_100HZ_2G: Default100Hz2G = Config {
+    data_rate: ctrl_reg1::odr::F100Hz,
+    power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+    axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+    full_scale: ctrl_reg4::fs::S2G,
+    resolution_mode: ctrl_reg4::hr::NormalResolution,
+    block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+    endianness: ctrl_reg4::ble::LittleEndian,
+    adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+    temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+    fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+    fifo_mode: fifo_ctrl_reg::fm::Bypass,
+    fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+};
+
+/// Type of [`LOW_POWER_10HZ`].
+pub type LowPower10Hz = Config<
+    ctrl_reg1::odr::F10Hz,
+    ctrl_reg1::lp_en::LowPowerMode,
+    ctrl_reg1::axis_enable::XYZEnabled,
+    ctrl_reg4::fs::S2G,
+    ctrl_reg4::hr::NormalResolution,
+    ctrl_reg4::bdu::ContinuousDataUpdate,
+    ctrl_reg4::ble::LittleEndian,
+    temp_cfg_reg::adc_en::AdcDisabled,
+    temp_cfg_reg::temp_en::TempDisabled,
+    ctrl_reg5::fifo_en::FifoDisabled,
+    fifo_ctrl_reg::fm::Bypass,
+    fifo_ctrl_reg::fth::Watermark<0>,
+>;
+
+/// 10 Hz, ±2 g, 8-bit low power mode with all axes enabled, for battery powered motion detection. Everything else is left at its power-on default.
+pub const LOW_POWER_10HZ: LowPower10Hz = Config {
+    data_rate: ctrl_reg1::odr::F10Hz,
+    power_mode: ctrl_reg1::lp_en::LowPowerMode,
+    axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+    full_scale: ctrl_reg4::fs::S2G,
+    resolution_mode: ctrl_reg4::hr::NormalResolution,
+    block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+    endianness: ctrl_reg4::ble::LittleEndian,
+    adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+    temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+    fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+    fifo_mode: fifo_ctrl_reg::fm::Bypass,
+    fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+};
@@ -1,23 +1,128 @@
 #![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_main)]
 
 pub mod acceleration_data_structs;
+pub mod blocking;
 pub mod bus;
 pub mod config;
 pub mod properties;
 pub mod registers;
 
-use crate::acceleration_data_structs::{Acceleration, AccelerationVector};
+use core::fmt;
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital;
+
+use crate::acceleration_data_structs::{
+    Acceleration, AccelerationVector, ZERO_ACCELERATION_VECTOR,
+};
 use crate::bus::Lis3dhBus;
 use crate::config::ValidLis3dhConfig;
-use crate::properties::resolution;
-use crate::registers::{ReadOnlyRegisterAddress, ReadWriteRegisterAddress, RegisterAddress};
+use crate::properties::odr_hz::{self, Duration};
+use crate::properties::threshold_coefficient::{self, Threshold};
+use crate::properties::{gravity_coefficient, operating_mode, resolution};
+use crate::registers::click_cfg::{ClickAxes, ClickConfig, DoubleTapTiming};
+use crate::registers::click_src::ClickSource;
+use crate::registers::ctrl_reg3::Int1Routing;
+use crate::registers::fifo_ctrl_reg::FifoTrigger;
+use crate::registers::fifo_src_reg::FifoStatus;
+use crate::registers::int1_src::{Face, InterruptSource};
+use crate::registers::int2_cfg::Int2Config;
+use crate::registers::out_adc::{self, AdcChannel};
+use crate::registers::status_reg::DataStatus;
+use crate::registers::status_reg_aux::AuxStatus;
+use crate::registers::who_am_i::DeviceId;
+use crate::registers::{
+    act_dur, act_ths, ctrl_reg1, ctrl_reg2, ctrl_reg3, ctrl_reg4, ctrl_reg5, ctrl_reg6,
+    fifo_ctrl_reg, int1_cfg, int1_duration, int2_duration, temp_cfg_reg, Entitled, Field, FromRaw,
+    ReadOnlyRegisterAddress, ReadWriteRegisterAddress, RegisterAddress,
+};
+
+/// Expected value of the `WHO_AM_I (0x0F)` register of a lis3dh.
+pub const LIS3DH_DEVICE_ID: u8 = 0x33;
+
+/// Time for the lis3dh to reload its trimming parameters after the `BOOT` bit is set.
+const BOOT_TIME_MS: u32 = 5;
+
+/// Turn-on time of low-power and normal mode after leaving power-down, on top of one output data rate period.
+const TURN_ON_OFFSET_US: f32 = 1000.0;
+
+/// Datasheet self-test output change window, in normal mode (10-bit) digits. Scaled to milli-g by [`Lis3dh::self_test_window_mg`].
+const SELF_TEST_MIN_LSB: i32 = 17;
+const SELF_TEST_MAX_LSB: i32 = 360;
+
+/// Time for the output to settle after the self-test is enabled or disabled.
+const SELF_TEST_SETTLING_TIME_MS: u32 = 90;
+
+/// Number of samples averaged before and during the self-test.
+const SELF_TEST_SAMPLES: u8 = 5;
+
+/// Contiguous blocks of readable, non-reserved register addresses as `(first, last)`, see the register map in [`registers`].
+const READABLE_REGISTER_BLOCKS: [(u8, u8); 3] = [(0x07, 0x0D), (0x0F, 0x0F), (0x1E, 0x3F)];
+
+/// Backoff between attempts of [`Lis3dh::read_accel_vector_with_retry`].
+const RETRY_BACKOFF_US: u32 = 500;
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error<BusErrorType> {
     /// # Bus error
     /// An error originating from the bus communication method (I2C or SPI) used as the communication method between the controller and the Lis3dh.
     Bus(BusErrorType),
+    /// # Invalid config
+    /// A runtime configuration ([`config::DynamicConfig`]) failed validation and was not written.
+    InvalidConfig(config::ConfigError),
+    /// # Invalid device
+    /// `WHO_AM_I (0x0F)` didn't read [`LIS3DH_DEVICE_ID`], so the device on the bus is either missing, miswired or not a lis3dh.
+    InvalidDevice { found: u8 },
+    /// # Timeout
+    /// The lis3dh didn't reach the awaited state within the allowed number of polls.
+    Timeout,
+    /// # Interrupt pin
+    /// The MCU pin wired to a lis3dh interrupt output failed while being awaited.
+    InterruptPin,
+    /// # Invalid argument
+    /// An argument was outside the range the command accepts, e.g. averaging zero samples.
+    InvalidArgument,
+    /// # Invalid field value
+    /// A field read back with [`Lis3dh::read_field`] held a raw value that isn't one of its variants, e.g. a reserved output data rate.
+    InvalidFieldValue { raw: u8 },
+    /// # Invalid range
+    /// A register range passed to [`Lis3dh::read_register_range`] was empty or crossed a reserved address or the end of the register map.
+    InvalidRange,
+    /// # Threshold out of range
+    /// A threshold or level was larger than its register field can hold, e.g. a FIFO watermark above 31.
+    ThresholdOutOfRange,
+    /// # Verification failed
+    /// A value read back after writing it didn't match, e.g. because the write didn't reach the lis3dh intact.
+    VerificationFailed { expected: u8, found: u8 },
+}
+
+// Written by hand rather than derived so the only bound is on the bus error, like the `defmt::Format` derive above.
+impl<BusErrorType: fmt::Debug> fmt::Debug for Error<BusErrorType> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Bus(error) => f.debug_tuple("Bus").field(error).finish(),
+            Error::InvalidConfig(error) => f.debug_tuple("InvalidConfig").field(error).finish(),
+            Error::InvalidDevice { found } => f
+                .debug_struct("InvalidDevice")
+                .field("found", found)
+                .finish(),
+            Error::Timeout => f.write_str("Timeout"),
+            Error::InterruptPin => f.write_str("InterruptPin"),
+            Error::InvalidArgument => f.write_str("InvalidArgument"),
+            Error::InvalidFieldValue { raw } => f
+                .debug_struct("InvalidFieldValue")
+                .field("raw", raw)
+                .finish(),
+            Error::InvalidRange => f.write_str("InvalidRange"),
+            Error::ThresholdOutOfRange => f.write_str("ThresholdOutOfRange"),
+            Error::VerificationFailed { expected, found } => f
+                .debug_struct("VerificationFailed")
+                .field("expected", expected)
+                .field("found", found)
+                .finish(),
+        }
+    }
 }
 
 impl<BusErrorType> From<BusErrorType> for Error<BusErrorType> {
@@ -26,15 +131,203 @@ impl<BusErrorType> From<BusErrorType> for Error<BusErrorType> {
     }
 }
 
+/// Result of [`Lis3dh::self_check`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HealthReport {
+    /// `WHO_AM_I` reads [`LIS3DH_DEVICE_ID`].
+    pub device_id_valid: bool,
+    /// The bits of `CTRL_REG0` that must stay set for correct operation are set.
+    pub ctrl_reg0_valid: bool,
+    /// `STATUS_REG` reports new (or overrun) XYZ data since the acceleration was last read.
+    pub data_updating: bool,
+    /// `FIFO_SRC_REG` reports a FIFO overrun.
+    pub fifo_overrun: bool,
+}
+
+impl HealthReport {
+    /// The lis3dh responds with the right identity and configuration, data is updating, and no samples were lost to a FIFO overrun.
+    pub fn is_healthy(&self) -> bool {
+        self.device_id_valid && self.ctrl_reg0_valid && self.data_updating && !self.fifo_overrun
+    }
+}
+
+/// Interrupt output pin of the lis3dh that an interrupt source is routed to.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InterruptPin {
+    /// INT1, routed through `CTRL_REG3 (0x22)`.
+    Int1,
+    /// INT2, routed through `CTRL_REG6 (0x25)`.
+    Int2,
+}
+
+/// Result of [`Lis3dh::run_self_test`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy)]
+pub struct SelfTestResult {
+    /// Absolute change of the averaged `[x, y, z]` output caused by the self-test, in milli-g.
+    pub delta_mg: [i32; 3],
+    /// Whether each `[x, y, z]` axis' change is within the datasheet self-test output change window.
+    pub axis_passed: [bool; 3],
+    /// Every axis' change is within the datasheet self-test output change window.
+    pub passed: bool,
+}
+
+/// Result of one axis of [`Lis3dh::run_self_test_per_axis`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy)]
+pub struct AxisSelfTest {
+    /// Absolute change of the averaged axis output caused by the self-test, in milli-g.
+    pub delta_mg: i32,
+    /// The change is within the datasheet self-test output change window.
+    pub passed: bool,
+}
+
+/// Result of [`Lis3dh::read_fifo`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FifoRead {
+    /// Number of samples written to the output buffer.
+    pub sample_count: usize,
+    /// `OVRN_FIFO` was set before draining: the FIFO was full and, in stream mode, older samples were overwritten.
+    pub overrun: bool,
+}
+
+/// An interrupt event yielded by [`Lis3dh::event_stream`], with the data read to service it.
+#[cfg(feature = "stream")]
+#[derive(Clone, Copy)]
+pub enum Event {
+    /// INT1 signalled data-ready; carries the new sample, whose read cleared the data-ready signal.
+    DataReady(AccelerationVector),
+    /// INT2 signalled motion; carries the decoded `INT2_SRC`, whose read cleared a latched interrupt.
+    Motion(InterruptSource),
+}
+
+/// Lazily drains the FIFO one sample at a time, returned by [`Lis3dh::fifo_iter`].
+pub struct FifoIter<'a, Bus, Config>
+where
+    Bus: Lis3dhBus,
+    Config: ValidLis3dhConfig,
+{
+    lis3dh: &'a mut Lis3dh<Bus, Config>,
+}
+
+impl<Bus, Config> FifoIter<'_, Bus, Config>
+where
+    Bus: Lis3dhBus,
+    Config: ValidLis3dhConfig,
+    Config::Odr: ctrl_reg1::odr::Active,
+{
+    /// Reads `FIFO_SRC_REG (0x2F)` and, unless `EMPTY` is set, pops the next sample from the FIFO. Returns `None` once the FIFO is empty.
+    ///
+    /// Bus errors are returned as `Some(Err)`, and iterating can carry on afterwards.
+    pub async fn next(&mut self) -> Option<Result<AccelerationVector, Error<Bus::BusError>>> {
+        match self.lis3dh.read_fifo_status().await {
+            Ok(fifo_status) if fifo_status.empty => None,
+            Ok(_) => Some(self.lis3dh.get_accel_vector().await),
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+/// Writes the rendered register values of a configuration to the lis3dh.
+pub(crate) async fn write_config_bytes<Bus: Lis3dhBus>(
+    bus: &mut Bus,
+    config_bytes: config::ConfigAsBytes,
+) -> Result<(), Error<Bus::BusError>> {
+    // Write Block 1: CtrlReg0 (0x1E) to CtrlReg6 (0x25)
+    // SAFETY: Starting memory address `CtrlReg0 = 0x1E` incremented 7 times leads to `CtrlReg6 = 0x25` which are all writable memory addresses.
+    unsafe {
+        bus.write_multiple(
+            ReadWriteRegisterAddress::CtrlReg0,
+            &config_bytes.ctrl_reg0_to_ctrl_reg6(),
+        )
+        .await?
+    };
+
+    // Write Block 2: FifoCtrlReg (0x2E)
+    bus.write(
+        ReadWriteRegisterAddress::FifoCtrlReg,
+        config_bytes.fifo_ctrl_reg,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Convenience function to combine an output register pair's bytes, in register address order, based on the configured endianness.
+pub(crate) fn raw_into_i16<Config: ValidLis3dhConfig>(raw_bytes: [u8; 2]) -> i16 {
+    match <Config::Ble as ctrl_reg4::ble::State>::VARIANT {
+        ctrl_reg4::ble::Variant::LittleEndian => i16::from_le_bytes(raw_bytes),
+        ctrl_reg4::ble::Variant::BigEndian => i16::from_be_bytes(raw_bytes),
+    }
+}
+
+/// Convenience function to perform the combination of an axis' acceleration bytes then adjusts based on configured resolution.
+///
+/// The output is left-justified, so the arithmetic shift of the signed value sign-extends the data and discards the unused low bits, e.g. 8-bit `0xFF00` decodes to -1 and `0x7FFF` to 127. The decoded range is the full range of the resolution: -128 to 127, -512 to 511 and -2048 to 2047; a reading at either end may be saturated.
+pub(crate) fn accel_raw_into_i16<Config: ValidLis3dhConfig>(raw_bytes: [u8; 2]) -> i16 {
+    raw_into_i16::<Config>(raw_bytes)
+        >> (16 - <Config::Resolution as resolution::Property>::VARIANT as u8)
+}
+
+/// Waits for `pin` to reach its active level, low if `active_low` and high otherwise.
+#[cfg(feature = "stream")]
+async fn wait_for_active_level<P: digital::Wait>(
+    pin: &mut P,
+    active_low: bool,
+) -> Result<(), P::Error> {
+    if active_low {
+        pin.wait_for_low().await
+    } else {
+        pin.wait_for_high().await
+    }
+}
+
+/// Decodes the bytes of `OUT_X_L (0x28)` to `OUT_Z_H (0x2D)` into the resolution adjusted acceleration vector.
+pub(crate) fn accel_bytes_into_vector<Config: ValidLis3dhConfig>(
+    accel_bytes: [u8; 6],
+) -> AccelerationVector {
+    let [a_x_0, a_x_1, a_y_0, a_y_1, a_z_0, a_z_1] = accel_bytes;
+    let x = Acceleration::new(accel_raw_into_i16::<Config>([a_x_0, a_x_1]));
+    let y = Acceleration::new(accel_raw_into_i16::<Config>([a_y_0, a_y_1]));
+    let z = Acceleration::new(accel_raw_into_i16::<Config>([a_z_0, a_z_1]));
+    AccelerationVector { x, y, z }
+}
+
+/// Returns the time in microseconds for the output of `Config` to settle after leaving power-down, or 0 with an output data rate of power-down.
+pub(crate) fn turn_on_time_us<Config: ValidLis3dhConfig>() -> u32 {
+    let odr_hz = <Config::OdrHz as odr_hz::Property>::FREQUENCY_HZ;
+    if odr_hz == 0.0 {
+        return 0;
+    }
+    let turn_on_time_us = match <Config::Resolution as resolution::Property>::VARIANT {
+        resolution::Variant::R12Bit => 7_000_000.0 / odr_hz,
+        resolution::Variant::R8Bit | resolution::Variant::R10Bit => {
+            1_000_000.0 / odr_hz + TURN_ON_OFFSET_US
+        }
+    };
+    turn_on_time_us as u32
+}
+
+/// Convenience function to combine an ADC output register pair's bytes into the right-justified count, which is 8-bit in low power mode and 10-bit otherwise.
+pub(crate) fn adc_raw_into_i16<Config: ValidLis3dhConfig>(raw_bytes: [u8; 2]) -> i16 {
+    let adc_as_i16 = raw_into_i16::<Config>(raw_bytes);
+    match <Config::Resolution as resolution::Property>::VARIANT {
+        resolution::Variant::R8Bit => adc_as_i16 >> 8,
+        resolution::Variant::R10Bit | resolution::Variant::R12Bit => adc_as_i16 >> 6,
+    }
+}
+
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[cfg_attr(not(feature = "defmt"), expect(unused))]
 pub struct Lis3dh<Bus, Config>
 where
     Bus: Lis3dhBus,
     Config: config::ValidLis3dhConfig,
 {
     bus: Bus,
-    config: Config, // Maybe needed in the future.
+    config: Config,
 }
 
 impl<Bus, Config> Lis3dh<Bus, Config>
@@ -42,31 +335,38 @@ where
     Bus: Lis3dhBus,
     Config: ValidLis3dhConfig,
 {
+    /// Verifies the device on the bus identifies as a lis3dh, then writes the config to it.
+    ///
+    /// Returns [`Error::InvalidDevice`] without writing anything if `WHO_AM_I` doesn't read [`LIS3DH_DEVICE_ID`].
     pub async fn new(mut bus: Bus, config: Config) -> Result<Self, Error<Bus::BusError>> {
-        let config::ConfigAsBytes {
-            ctrl_reg0: ctrl_reg0_bytes,
-            temp_cfg_reg: temp_cfg_reg_bytes,
-            ctrl_reg1: ctrl_reg1_bytes,
-            ctrl_reg4: ctrl_reg4_bytes,
-        } = Config::render_as_bytes();
-
-        // Write Block 1: CtrlReg0 (0x1E) to CtrlReg1 (0x20)
-        let config_write_block_ctrl_reg0_to_ctrl_reg1 =
-            [ctrl_reg0_bytes, temp_cfg_reg_bytes, ctrl_reg1_bytes];
-
-        // SAFETY: Starting memory address `CtrlReg0 = 0x1E` incremented 2 times leads to `CtrlReg1 = 0x20` which are all writable memory addresses.
-        unsafe {
-            bus.write_multiple(
-                ReadWriteRegisterAddress::CtrlReg0,
-                &config_write_block_ctrl_reg0_to_ctrl_reg1,
-            )
-            .await?
-        };
+        let who_am_i = bus.read(ReadOnlyRegisterAddress::WhoAmI).await?;
+        if who_am_i != LIS3DH_DEVICE_ID {
+            return Err(Error::InvalidDevice { found: who_am_i });
+        }
+        Self::new_unchecked(bus, config).await
+    }
 
-        // Write Block 2: CtrlReg4 (0x23)
-        bus.write(ReadWriteRegisterAddress::CtrlReg4, ctrl_reg4_bytes)
-            .await?;
+    /// Like [`Lis3dh::new`], but honours the lis3dh's timing so the first sample read after returning is valid.
+    ///
+    /// Waits the boot time for the lis3dh to load its trimming parameters after power-up, identifies and configures it, then waits the turn-on time of the configured operating mode from power-down, see AN3308 table 12:
+    /// - Low-power and normal mode: 1/ODR + 1 ms.
+    /// - High-resolution mode: 7/ODR.
+    ///
+    /// No turn-on time is waited with an output data rate of power-down. To also reload the trimming parameters, e.g. after a brown-out, call [`Lis3dh::reboot`] afterwards.
+    pub async fn new_with_delay<D: DelayNs>(
+        bus: Bus,
+        config: Config,
+        delay: &mut D,
+    ) -> Result<Self, Error<Bus::BusError>> {
+        delay.delay_ms(BOOT_TIME_MS).await;
+        let lis3dh = Self::new(bus, config).await?;
+        delay.delay_us(turn_on_time_us::<Config>()).await;
+        Ok(lis3dh)
+    }
 
+    /// Writes the config without checking `WHO_AM_I`, e.g. for pin-compatible clones reporting a different device ID.
+    pub async fn new_unchecked(mut bus: Bus, config: Config) -> Result<Self, Error<Bus::BusError>> {
+        write_config_bytes(&mut bus, Config::render_as_bytes()).await?;
         Ok(Lis3dh { bus, config })
     }
 
@@ -78,19 +378,598 @@ where
     where
         NewConfig: ValidLis3dhConfig,
     {
-        Lis3dh::new(self.bus, new_config).await
+        // The device was already identified when `self` was constructed.
+        Lis3dh::new_unchecked(self.bus, new_config).await
+    }
+
+    /// Consumes the driver and returns the owned bus, e.g. to share the peripheral with another device.
+    pub fn release(self) -> Bus {
+        let Lis3dh { bus, .. } = self;
+        bus
+    }
+
+    /// Reboots the lis3dh's memory content by setting the `BOOT` bit of `CTRL_REG5`, which reloads the factory trimming parameters, then waits for the boot to complete.
+    ///
+    /// The config is re-written afterwards so the device matches `Config` again.
+    pub async fn reboot<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<Bus::BusError>> {
+        use ctrl_reg5::boot;
+
+        let ctrl_reg5 = self.bus.read(ReadWriteRegisterAddress::CtrlReg5).await?;
+        self.bus
+            .write(
+                ReadWriteRegisterAddress::CtrlReg5,
+                ctrl_reg5 | (boot::Variant::RebootMemoryContent as u8) << boot::OFFSET,
+            )
+            .await?;
+        delay.delay_ms(BOOT_TIME_MS).await;
+        write_config_bytes(&mut self.bus, Config::render_as_bytes()).await
+    }
+
+    /// Writes the datasheet reset value to every writable register the driver models, putting the lis3dh back to its power-on state without a reboot, e.g. for test teardown or error recovery.
+    ///
+    /// `CTRL_REG0 (0x1E)` to `CTRL_REG6 (0x25)` are written with [`config::DEFAULT_CONFIG_BYTES`] and `FIFO_CTRL_REG (0x2E)` is rendered from [`config::presets::PowerOnDefault`], so they follow the `Default` type-state of every field. `REFERENCE (0x26)` and the interrupt generator, click and activity registers are cleared to 0. Unlike [`Lis3dh::reboot`], the factory trimming parameters aren't reloaded.
+    ///
+    /// The lis3dh is powered down afterwards, so the driver is returned with the matching config.
+    pub async fn reset_to_defaults(
+        mut self,
+    ) -> Result<Lis3dh<Bus, config::presets::PowerOnDefault>, Error<Bus::BusError>> {
+        // Writable registers outside the config, all resetting to 0, as (first register, number of registers) blocks.
+        const CLEARED_BLOCKS: [(ReadWriteRegisterAddress, usize); 7] = [
+            (ReadWriteRegisterAddress::Reference, 1),
+            (ReadWriteRegisterAddress::Int1Cfg, 1),
+            (ReadWriteRegisterAddress::Int1Ths, 2),
+            (ReadWriteRegisterAddress::Int2Cfg, 1),
+            (ReadWriteRegisterAddress::Int2Ths, 2),
+            (ReadWriteRegisterAddress::ClickCfg, 1),
+            (ReadWriteRegisterAddress::ClickThs, 6),
+        ];
+
+        // SAFETY: Starting memory address `CtrlReg0 = 0x1E` incremented 7 times leads to `CtrlReg6 = 0x25` which are all writable memory addresses.
+        unsafe {
+            self.bus
+                .write_multiple(
+                    ReadWriteRegisterAddress::CtrlReg0,
+                    &config::DEFAULT_CONFIG_BYTES,
+                )
+                .await?
+        };
+        self.bus
+            .write(
+                ReadWriteRegisterAddress::FifoCtrlReg,
+                <config::presets::PowerOnDefault as ValidLis3dhConfig>::render_as_bytes()
+                    .fifo_ctrl_reg,
+            )
+            .await?;
+        for (start_address, register_count) in CLEARED_BLOCKS {
+            // SAFETY: Each block only spans consecutive writable registers, e.g. `Int1Ths = 0x32` to `Int1Duration = 0x33`, and the longest `ClickThs = 0x3A` to `ActDur = 0x3F`.
+            unsafe {
+                self.bus
+                    .write_multiple(start_address, &[0; 6][..register_count])
+                    .await?
+            };
+        }
+
+        let Lis3dh { bus, .. } = self;
+        Ok(Lis3dh {
+            bus,
+            config: config::presets::POWER_ON_DEFAULT,
+        })
+    }
+
+    /// Returns the `(address, value)` pairs [`Lis3dh::new`] writes for `Config`, in write order, without any bus traffic. Useful for snapshotting configurations in tests.
+    pub fn config_bytes(&self) -> [(u8, u8); 9] {
+        Config::render_as_bytes().register_values()
+    }
+
+    /// Returns the configured full scale range in ±g: 2, 4, 8 or 16. Derived from `Config` without any bus traffic.
+    pub fn full_scale_g(&self) -> u8 {
+        match <Config::Fs as ctrl_reg4::fs::State>::VARIANT {
+            ctrl_reg4::fs::Variant::S2G => 2,
+            ctrl_reg4::fs::Variant::S4G => 4,
+            ctrl_reg4::fs::Variant::S8G => 8,
+            ctrl_reg4::fs::Variant::S16G => 16,
+        }
+    }
+
+    /// Returns the configured acceleration resolution in bits: 8, 10 or 12. Derived from `Config` without any bus traffic.
+    pub fn resolution_bits(&self) -> u8 {
+        <Config::Resolution as resolution::Property>::VARIANT as u8
+    }
+
+    /// Returns the usable (Nyquist) bandwidth in Hz, half the configured output data rate. Derived from `Config` without any bus traffic.
+    pub fn bandwidth_hz(&self) -> f32 {
+        <Config::OdrHz as odr_hz::Property>::BANDWIDTH_HZ
     }
 
     pub async fn read_who_am_i(&mut self) -> Result<u8, Error<Bus::BusError>> {
         Ok(self.bus.read(ReadOnlyRegisterAddress::WhoAmI).await?)
     }
 
-    /// Convenience function to perform the combination of lower & upper acceleration values then adjusts based on configured resolution.
-    fn accel_raw_into_i16(lower_byte: u8, upper_byte: u8) -> i16 {
-        let accel_as_i16 = i16::from_le_bytes([lower_byte, upper_byte]);
-        accel_as_i16 >> (16 - <Config::Resolution as resolution::Property>::VARIANT as u8)
+    /// Reads `WHO_AM_I (0x0F)` as a [`DeviceId`], e.g. to probe for a lis3dh with [`DeviceId::is_lis3dh`].
+    pub async fn read_device_id(&mut self) -> Result<DeviceId, Error<Bus::BusError>> {
+        Ok(DeviceId::from(self.read_who_am_i().await?))
+    }
+
+    /// Lightweight health probe for periodic monitoring, reading `WHO_AM_I`, `CTRL_REG0`, `STATUS_REG` and `FIFO_SRC_REG`. Unlike a self-test it doesn't disturb the configuration or measurements.
+    ///
+    /// "Healthy" (see [`HealthReport::is_healthy`]) means the device identifies as a lis3dh, still holds its mandatory `CTRL_REG0` bits, has produced a sample since the acceleration was last read, and has not overrun its FIFO.
+    /// `data_updating` is derived from the data-available flags, which the lis3dh clears when the output registers are read. Calling this immediately after a read, before the next sample is due, reports `false` even on a healthy sensor, as does a configuration with an output data rate of power-down.
+    pub async fn self_check(&mut self) -> Result<HealthReport, Error<Bus::BusError>> {
+        use registers::ctrl_reg0::must_set_bits;
+
+        const MUST_SET_BITS_MASK: u8 = ((1 << must_set_bits::WIDTH) - 1) << must_set_bits::OFFSET;
+
+        let who_am_i = self.read_who_am_i().await?;
+        let ctrl_reg0 = self.bus.read(ReadWriteRegisterAddress::CtrlReg0).await?;
+        let data_status = self.read_status().await?;
+        let fifo_status = self.read_fifo_status().await?;
+
+        Ok(HealthReport {
+            device_id_valid: who_am_i == LIS3DH_DEVICE_ID,
+            ctrl_reg0_valid: ctrl_reg0 & MUST_SET_BITS_MASK
+                == (must_set_bits::Variant::MustSet as u8) << must_set_bits::OFFSET,
+            data_updating: data_status.zyx_ready || data_status.zyx_overrun,
+            fifo_overrun: fifo_status.overrun,
+        })
+    }
+
+    /// Re-reads every register written by the config and returns true if they all still hold the rendered config, catching writes corrupted by a glitching bus.
+    ///
+    /// `CTRL_REG0 (0x1E)` to `CTRL_REG1 (0x20)` is checked with one burst, then `CTRL_REG4 (0x23)`, `CTRL_REG5 (0x24)` and `FIFO_CTRL_REG (0x2E)`. `CTRL_REG2`, `CTRL_REG3` and `CTRL_REG6` are only written at their reset values and then left to runtime commands, so they aren't checked, and neither are the interrupt latch bits of `CTRL_REG5`, so commands such as [`Lis3dh::configure_int1_routing`] and [`Lis3dh::set_int1_latched`] don't affect the result.
+    pub async fn verify_config(&mut self) -> Result<bool, Error<Bus::BusError>> {
+        use ctrl_reg5::{boot, fifo_en};
+        const CTRL_REG5_CONFIG_MASK: u8 = (1 << boot::OFFSET) | (1 << fifo_en::OFFSET);
+
+        let config::ConfigAsBytes {
+            ctrl_reg0,
+            temp_cfg_reg,
+            ctrl_reg1,
+            ctrl_reg4,
+            ctrl_reg5,
+            fifo_ctrl_reg,
+            ..
+        } = Config::render_as_bytes();
+
+        Ok(self
+            .bus
+            .read_and_verify_multiple(
+                ReadWriteRegisterAddress::CtrlReg0,
+                &[ctrl_reg0, temp_cfg_reg, ctrl_reg1],
+            )
+            .await?
+            && self
+                .bus
+                .read_and_verify(ReadWriteRegisterAddress::CtrlReg4, &ctrl_reg4)
+                .await?
+            && self.bus.read(ReadWriteRegisterAddress::CtrlReg5).await? & CTRL_REG5_CONFIG_MASK
+                == ctrl_reg5 & CTRL_REG5_CONFIG_MASK
+            && self
+                .bus
+                .read_and_verify(ReadWriteRegisterAddress::FifoCtrlReg, &fifo_ctrl_reg)
+                .await?)
+    }
+
+    /// Reads and decodes `STATUS_REG (0x27)`, e.g. to skip reads in a polling loop until [`DataStatus::zyx_ready`] is set.
+    pub async fn read_status(&mut self) -> Result<DataStatus, Error<Bus::BusError>> {
+        let status_reg = self.bus.read(ReadOnlyRegisterAddress::StatusReg).await?;
+        Ok(DataStatus::from(status_reg))
+    }
+
+    /// Returns the `ZYXDA` bit of `STATUS_REG (0x27)`: new data is available on all axes.
+    pub async fn is_data_ready(&mut self) -> Result<bool, Error<Bus::BusError>> {
+        Ok(self.read_status().await?.zyx_ready)
+    }
+
+    /// Reads and decodes `STATUS_REG_AUX (0x07)` and `STATUS_REG (0x27)`, e.g. to check both the ADC and acceleration data ready flags before reading them together.
+    ///
+    /// The registers aren't adjacent, so they are read with two single-byte reads.
+    pub async fn read_all_status(
+        &mut self,
+    ) -> Result<(AuxStatus, DataStatus), Error<Bus::BusError>> {
+        let status_reg_aux = self.bus.read(ReadOnlyRegisterAddress::StatusRegAux).await?;
+        Ok((AuxStatus::from(status_reg_aux), self.read_status().await?))
+    }
+
+    /// Reads and decodes `FIFO_SRC_REG (0x2F)`.
+    pub async fn read_fifo_status(&mut self) -> Result<FifoStatus, Error<Bus::BusError>> {
+        let fifo_src_reg = self.bus.read(ReadOnlyRegisterAddress::FifoSrcReg).await?;
+        Ok(FifoStatus::from(fifo_src_reg))
+    }
+
+    /// Clears the FIFO by switching `FIFO_CTRL_REG (0x2E)` to bypass mode and back to the configured mode, as recommended by the datasheet. Unread samples are discarded and `OVRN_FIFO` is cleared.
+    pub async fn reset_fifo(&mut self) -> Result<(), Error<Bus::BusError>> {
+        use fifo_ctrl_reg::fm;
+
+        let fifo_ctrl_reg = Config::render_as_bytes().fifo_ctrl_reg;
+        let bypass = fifo_ctrl_reg & !(((1 << fm::WIDTH) - 1) << fm::OFFSET)
+            | (fm::Variant::Bypass as u8) << fm::OFFSET;
+        self.bus
+            .write(ReadWriteRegisterAddress::FifoCtrlReg, bypass)
+            .await?;
+        self.bus
+            .write(ReadWriteRegisterAddress::FifoCtrlReg, fifo_ctrl_reg)
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the `WTM` bit of `FIFO_SRC_REG (0x2F)`: the FIFO holds more samples than the configured watermark.
+    pub async fn is_fifo_watermark_reached(&mut self) -> Result<bool, Error<Bus::BusError>> {
+        Ok(self.read_fifo_status().await?.watermark)
+    }
+
+    /// Returns the `OVRN_FIFO` bit of `FIFO_SRC_REG (0x2F)`: the FIFO holds all 32 samples.
+    ///
+    /// What "full" means depends on the FIFO mode: in FIFO mode the FIFO stops collecting once full, so no sample is lost but new ones are dropped until it is read; in stream mode it keeps running and overwrites its oldest sample with every new one.
+    pub async fn is_fifo_full(&mut self) -> Result<bool, Error<Bus::BusError>> {
+        Ok(self.read_fifo_status().await?.overrun)
+    }
+
+    /// Returns the `EMPTY` bit of `FIFO_SRC_REG (0x2F)`: every sample has been read, e.g. to end a drain loop.
+    pub async fn is_fifo_empty(&mut self) -> Result<bool, Error<Bus::BusError>> {
+        Ok(self.read_fifo_status().await?.empty)
+    }
+
+    /// Selects the interrupt generator that triggers stream-to-FIFO mode by read-modify-writing only the `TR` field of `FIFO_CTRL_REG (0x2E)`.
+    ///
+    /// The trigger only has an effect in [`fifo_ctrl_reg::fm::StreamToFifo`], so selecting [`fifo_ctrl_reg::tr::Int2`] with any other configured FIFO mode fails to compile. Like [`Lis3dh::set_fifo_watermark`], the selection isn't part of `Config` and is reset to [`fifo_ctrl_reg::tr::Int1`] when the config is written.
+    pub async fn set_fifo_trigger<Tr>(&mut self, _trigger: Tr) -> Result<(), Error<Bus::BusError>>
+    where
+        Tr: fifo_ctrl_reg::tr::State + Entitled<Config::Fm>,
+    {
+        use fifo_ctrl_reg::tr;
+
+        self.modify_field(
+            ReadWriteRegisterAddress::FifoCtrlReg,
+            tr::OFFSET,
+            tr::WIDTH,
+            Tr::VARIANT as u8,
+        )
+        .await
+    }
+
+    /// Configures click (tap) detection by writing `CLICK_CFG (0x38)` and `CLICK_THS (0x3A)` to `TIME_WINDOW (0x3D)`.
+    ///
+    /// The interrupt still has to be routed to an interrupt pin to be signalled externally; otherwise poll [`Lis3dh::read_click_src`].
+    pub async fn configure_click(&mut self, cfg: ClickConfig) -> Result<(), Error<Bus::BusError>> {
+        self.bus
+            .write(ReadWriteRegisterAddress::ClickCfg, cfg.click_cfg_byte())
+            .await?;
+
+        // SAFETY: Starting memory address `ClickThs = 0x3A` incremented 3 times leads to `TimeWindow = 0x3D` which are all writable memory addresses.
+        unsafe {
+            self.bus
+                .write_multiple(ReadWriteRegisterAddress::ClickThs, &cfg.timing_bytes())
+                .await?
+        };
+        Ok(())
+    }
+
+    /// Configures double tap detection on all axes with [`Lis3dh::configure_click`], then routes the click interrupt to `pin` by read-modify-writing `I1_CLICK` in `CTRL_REG3 (0x22)` or `I2_CLICK` in `CTRL_REG6 (0x25)`, keeping the other routed sources.
+    ///
+    /// The threshold is converted like in [`Lis3dh::configure_free_fall`] and the times are converted to the nearest number of output data rate periods. Returns [`Error::InvalidArgument`] without bus traffic if a non-zero time rounds to 0 periods because the output data rate is too low, or doesn't fit its register. The interrupt is latched until [`Lis3dh::read_click_src`] is called.
+    pub async fn configure_double_tap(
+        &mut self,
+        threshold_mg: u16,
+        timing_ms: DoubleTapTiming,
+        pin: InterruptPin,
+    ) -> Result<(), Error<Bus::BusError>> {
+        let [time_limit, time_latency, time_window] = timing_ms
+            .counts::<Config::OdrHz>()
+            .ok_or(Error::InvalidArgument)?;
+        let all_axes = ClickAxes {
+            x: true,
+            y: true,
+            z: true,
+        };
+        self.configure_click(ClickConfig {
+            single: ClickAxes::default(),
+            double: all_axes,
+            threshold: threshold_coefficient::milli_g_to_counts::<Config::ThresholdCoefficient>(
+                threshold_mg,
+            ),
+            latch_interrupt: true,
+            time_limit,
+            time_latency,
+            time_window,
+        })
+        .await?;
+
+        let (register_address, offset) = match pin {
+            InterruptPin::Int1 => (
+                ReadWriteRegisterAddress::CtrlReg3,
+                ctrl_reg3::i1_click::OFFSET,
+            ),
+            InterruptPin::Int2 => (
+                ReadWriteRegisterAddress::CtrlReg6,
+                ctrl_reg6::i2_click::OFFSET,
+            ),
+        };
+        self.modify_field(register_address, offset, 1, 1).await
+    }
+
+    /// Reads and decodes `CLICK_SRC (0x39)`. Reading clears a latched click interrupt.
+    pub async fn read_click_src(&mut self) -> Result<ClickSource, Error<Bus::BusError>> {
+        let click_src = self.bus.read(ReadOnlyRegisterAddress::ClickSrc).await?;
+        Ok(ClickSource::from(click_src))
+    }
+
+    /// Configures interrupt 1 to detect free-fall: an AND combination of low events on every axis, i.e. all axes reading below `threshold_mg` for at least `duration_samples` samples.
+    ///
+    /// The threshold is converted to the nearest count of the configured full scale (see [`properties::threshold_coefficient`]), and both the threshold and duration saturate at 127 counts.
+    pub async fn configure_free_fall(
+        &mut self,
+        threshold_mg: u16,
+        duration_samples: u8,
+    ) -> Result<(), Error<Bus::BusError>> {
+        const FREE_FALL_INT1_CFG: u8 = 1 << int1_cfg::AOI_OFFSET
+            | 1 << int1_cfg::ZLIE_OFFSET
+            | 1 << int1_cfg::YLIE_OFFSET
+            | 1 << int1_cfg::XLIE_OFFSET;
+
+        let threshold =
+            threshold_coefficient::milli_g_to_counts::<Config::ThresholdCoefficient>(threshold_mg);
+        let duration = duration_samples.min(int1_duration::D_MAX);
+
+        // SAFETY: Starting memory address `Int1Ths = 0x32` incremented once leads to `Int1Duration = 0x33` which are both writable memory addresses.
+        unsafe {
+            self.bus
+                .write_multiple(ReadWriteRegisterAddress::Int1Ths, &[threshold, duration])
+                .await?
+        };
+        self.bus
+            .write(ReadWriteRegisterAddress::Int1Cfg, FREE_FALL_INT1_CFG)
+            .await?;
+        Ok(())
+    }
+
+    /// Configures interrupt generator 2 by writing `INT2_THS (0x36)`, `INT2_DURATION (0x37)` and `INT2_CFG (0x34)`, independently of interrupt generator 1.
+    ///
+    /// The threshold is converted like in [`Lis3dh::configure_free_fall`]. Read the cause of the interrupt with [`Lis3dh::read_int2_src`].
+    pub async fn configure_int2(&mut self, cfg: Int2Config) -> Result<(), Error<Bus::BusError>> {
+        let threshold = threshold_coefficient::milli_g_to_counts::<Config::ThresholdCoefficient>(
+            cfg.threshold_mg,
+        );
+        let duration = cfg.duration_samples.min(int2_duration::D_MAX);
+
+        // SAFETY: Starting memory address `Int2Ths = 0x36` incremented once leads to `Int2Duration = 0x37` which are both writable memory addresses.
+        unsafe {
+            self.bus
+                .write_multiple(ReadWriteRegisterAddress::Int2Ths, &[threshold, duration])
+                .await?
+        };
+        self.bus
+            .write(ReadWriteRegisterAddress::Int2Cfg, cfg.int2_cfg_byte())
+            .await?;
+        Ok(())
+    }
+
+    /// Routes interrupt sources to the INT1 pin by writing `CTRL_REG3 (0x22)`, replacing any previous routing.
+    pub async fn configure_int1_routing<Click, Ia1, Ia2, Zyxda, Da321, Wtm, Overrun>(
+        &mut self,
+        routing: Int1Routing<Click, Ia1, Ia2, Zyxda, Da321, Wtm, Overrun>,
+    ) -> Result<(), Error<Bus::BusError>>
+    where
+        Click: ctrl_reg3::i1_click::State,
+        Ia1: ctrl_reg3::i1_ia1::State,
+        Ia2: ctrl_reg3::i1_ia2::State,
+        Zyxda: ctrl_reg3::i1_zyxda::State,
+        Da321: ctrl_reg3::i1_321da::State,
+        Wtm: ctrl_reg3::i1_wtm::State,
+        Overrun: ctrl_reg3::i1_overrun::State,
+    {
+        self.bus
+            .write(ReadWriteRegisterAddress::CtrlReg3, routing.render())
+            .await?;
+        Ok(())
+    }
+
+    /// Configures interrupt 1 for 6D position recognition, which flags the face of the lis3dh pointing up once the acceleration along it exceeds `threshold_mg`.
+    ///
+    /// The threshold is converted like in [`Lis3dh::configure_free_fall`]. Read the recognized position with [`Lis3dh::read_orientation`].
+    pub async fn configure_6d(&mut self, threshold_mg: u16) -> Result<(), Error<Bus::BusError>> {
+        const POSITION_6D_INT1_CFG: u8 = 1 << int1_cfg::AOI_OFFSET
+            | 1 << int1_cfg::D6_OFFSET
+            | 1 << int1_cfg::ZHIE_OFFSET
+            | 1 << int1_cfg::ZLIE_OFFSET
+            | 1 << int1_cfg::YHIE_OFFSET
+            | 1 << int1_cfg::YLIE_OFFSET
+            | 1 << int1_cfg::XHIE_OFFSET
+            | 1 << int1_cfg::XLIE_OFFSET;
+
+        let threshold =
+            threshold_coefficient::milli_g_to_counts::<Config::ThresholdCoefficient>(threshold_mg);
+
+        self.bus
+            .write(ReadWriteRegisterAddress::Int1Ths, threshold)
+            .await?;
+        self.bus
+            .write(ReadWriteRegisterAddress::Int1Cfg, POSITION_6D_INT1_CFG)
+            .await?;
+        Ok(())
+    }
+
+    /// Writes `INT1_THS (0x32)` from a threshold at the configured full scale, e.g. `Threshold::from_mg(250)`.
+    ///
+    /// The threshold's full scale must match the config's, so a threshold built for another full scale fails to compile.
+    pub async fn set_int1_threshold(
+        &mut self,
+        threshold: Threshold<Config::Fs>,
+    ) -> Result<(), Error<Bus::BusError>> {
+        self.bus
+            .write(ReadWriteRegisterAddress::Int1Ths, threshold.raw())
+            .await?;
+        Ok(())
+    }
+
+    /// Writes `INT1_DURATION (0x33)` from a duration at the configured output data rate, e.g. `Duration::from_ms(75)`.
+    ///
+    /// The duration's output data rate must match the config's, so a duration built for another output data rate fails to compile.
+    pub async fn set_int1_duration(
+        &mut self,
+        duration: Duration<Config::OdrHz>,
+    ) -> Result<(), Error<Bus::BusError>> {
+        self.bus
+            .write(ReadWriteRegisterAddress::Int1Duration, duration.raw())
+            .await?;
+        Ok(())
+    }
+
+    /// Reads `INT1_SRC (0x31)` and decodes the position recognized by [`Lis3dh::configure_6d`], or `None` if no position is recognized.
+    pub async fn read_orientation(&mut self) -> Result<Option<Face>, Error<Bus::BusError>> {
+        Ok(self.read_int1_src().await?.face())
+    }
+
+    /// Reads and decodes `INT1_SRC (0x31)`, i.e. which event made interrupt generator 1 fire.
+    ///
+    /// When interrupt 1 is latched (see [`Lis3dh::set_int1_latched`]) the read itself clears the latch and releases the interrupt line, so the returned source is the only record of the event.
+    pub async fn read_int1_src(&mut self) -> Result<InterruptSource, Error<Bus::BusError>> {
+        let int1_src = self.bus.read(ReadOnlyRegisterAddress::Int1Src).await?;
+        Ok(InterruptSource::from(int1_src))
+    }
+
+    /// Reads and decodes `INT2_SRC (0x35)`, i.e. which event made interrupt generator 2 fire.
+    ///
+    /// When interrupt 2 is latched (`LIR_INT2` in `CTRL_REG5 (0x24)`) the read itself clears the latch and releases the interrupt line, so the returned source is the only record of the event.
+    pub async fn read_int2_src(&mut self) -> Result<InterruptSource, Error<Bus::BusError>> {
+        let int2_src = self.bus.read(ReadOnlyRegisterAddress::Int2Src).await?;
+        Ok(InterruptSource::from(int2_src))
+    }
+
+    /// Writes `REFERENCE (0x26)`, the reference acceleration the high-pass filter subtracts from the output.
+    ///
+    /// Only has an effect when the high-pass filter mode (HPM) in `CTRL_REG2 (0x21)` is reference mode. `value` is in counts of the configured full scale, see [`properties::threshold_coefficient`].
+    pub async fn set_reference(&mut self, value: u8) -> Result<(), Error<Bus::BusError>> {
+        self.bus
+            .write(ReadWriteRegisterAddress::Reference, value)
+            .await?;
+        Ok(())
+    }
+
+    /// Reads `REFERENCE (0x26)`. Reading it also resets the high-pass filter to the current acceleration, which is how the filter is calibrated in normal mode.
+    ///
+    /// The returned value is only meaningful when the high-pass filter mode (HPM) in `CTRL_REG2 (0x21)` is reference mode.
+    pub async fn read_reference(&mut self) -> Result<u8, Error<Bus::BusError>> {
+        Ok(self.bus.read(ReadWriteRegisterAddress::Reference).await?)
+    }
+
+    /// Reads `TEMP_CFG_REG (0x1F)` and returns `(adc_enabled, temp_enabled)`, e.g. to confirm the config was written before reading the temperature sensor or ADC.
+    pub async fn read_temp_cfg(&mut self) -> Result<(bool, bool), Error<Bus::BusError>> {
+        let temp_cfg_reg = self.bus.read(ReadWriteRegisterAddress::TempCfgReg).await?;
+        let adc_enabled = temp_cfg_reg & (1 << temp_cfg_reg::adc_en::OFFSET) != 0;
+        let temp_enabled = temp_cfg_reg & (1 << temp_cfg_reg::temp_en::OFFSET) != 0;
+        Ok((adc_enabled, temp_enabled))
+    }
+
+    /// Sets whether interrupt 1 latches by read-modify-writing `LIR_INT1` in `CTRL_REG5 (0x24)`.
+    ///
+    /// When latched, `INT1_SRC` and the INT1 pin stay asserted after the event ends, so short events such as free-fall aren't missed; read `INT1_SRC` (e.g. with [`Lis3dh::read_int1_src`]) to clear the interrupt. Writing the config, e.g. with [`Lis3dh::reconfigure`], clears the latch again.
+    pub async fn set_int1_latched(&mut self, latched: bool) -> Result<(), Error<Bus::BusError>> {
+        use ctrl_reg5::lir_int1;
+
+        let ctrl_reg5 = self.bus.read(ReadWriteRegisterAddress::CtrlReg5).await?;
+        let lir_int1 = if latched {
+            lir_int1::Variant::Latched
+        } else {
+            lir_int1::Variant::NotLatched
+        };
+        self.bus
+            .write(
+                ReadWriteRegisterAddress::CtrlReg5,
+                ctrl_reg5 & !(1 << lir_int1::OFFSET) | (lir_int1 as u8) << lir_int1::OFFSET,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Waits for `pin`, wired to INT1, to reach its active level, then reads and clears `INT1_SRC (0x31)` and returns the decoded cause.
+    ///
+    /// The active level follows `INT_POLARITY` in `CTRL_REG6 (0x25)`, read before waiting. The level rather than an edge is awaited, so a latched interrupt that is already asserted returns immediately instead of being missed. Returns [`Error::InterruptPin`] if waiting on `pin` fails.
+    pub async fn wait_for_interrupt<P: digital::Wait>(
+        &mut self,
+        pin: &mut P,
+    ) -> Result<InterruptSource, Error<Bus::BusError>> {
+        let ctrl_reg6 = self.bus.read(ReadWriteRegisterAddress::CtrlReg6).await?;
+        let active_low = ctrl_reg6 & (1 << ctrl_reg6::int_polarity::OFFSET) != 0;
+
+        let asserted = if active_low {
+            pin.wait_for_low().await
+        } else {
+            pin.wait_for_high().await
+        };
+        asserted.map_err(|_| Error::InterruptPin)?;
+
+        self.read_int1_src().await
+    }
+
+    /// Configures sleep-to-wake by writing `ACT_THS (0x3E)` and `ACT_DUR (0x3F)`. Once acceleration falls below `threshold` for `duration` the lis3dh drops to 10 Hz low-power mode, returning to the configured mode when it is exceeded again.
+    ///
+    /// `threshold` is in counts of the configured full scale (see [`properties::threshold_coefficient`]) and saturates at 127. `duration` is in counts of `(8 * duration + 1) / ODR` seconds, see [`Lis3dh::activity_duration_from_ms`].
+    pub async fn configure_activity(
+        &mut self,
+        threshold: u8,
+        duration: u8,
+    ) -> Result<(), Error<Bus::BusError>> {
+        let threshold = threshold.min(act_ths::ACTH_MAX);
+
+        // SAFETY: Starting memory address `ActThs = 0x3E` incremented once leads to `ActDur = 0x3F` which are both writable memory addresses.
+        unsafe {
+            self.bus
+                .write_multiple(ReadWriteRegisterAddress::ActThs, &[threshold, duration])
+                .await?
+        };
+        Ok(())
+    }
+
+    /// Configures sleep-to-wake with [`Lis3dh::configure_activity`], then routes the activity interrupt to INT2, the only pin it is wired to, by read-modify-writing `I2_ACT` in `CTRL_REG6 (0x25)`, keeping the other routed sources, so the MCU can wake when the lis3dh leaves sleep.
+    pub async fn configure_activity_interrupt(
+        &mut self,
+        threshold: u8,
+        duration: u8,
+    ) -> Result<(), Error<Bus::BusError>> {
+        self.configure_activity(threshold, duration).await?;
+        self.modify_field(
+            ReadWriteRegisterAddress::CtrlReg6,
+            ctrl_reg6::i2_act::OFFSET,
+            ctrl_reg6::i2_act::WIDTH,
+            1,
+        )
+        .await
+    }
+
+    /// Converts a wake duration in milliseconds to `ACT_DUR` counts at the configured output data rate, see [`act_dur::counts_from_ms`].
+    pub fn activity_duration_from_ms(&self, duration_ms: u32) -> u8 {
+        act_dur::counts_from_ms::<Config::OdrHz>(duration_ms)
+    }
+
+    /// Replaces the `width` bit field at `offset` of a register with `value`, leaving its other bits untouched.
+    async fn modify_field(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        offset: u8,
+        width: u8,
+        value: u8,
+    ) -> Result<(), Error<Bus::BusError>> {
+        let mask = ((1 << width) - 1) << offset;
+        let register = self.bus.read(register_address).await?;
+        self.bus
+            .write(
+                register_address,
+                register & !mask | (value << offset) & mask,
+            )
+            .await?;
+        Ok(())
     }
+}
+
+// Acceleration commands.
+// Only available when the output data rate isn't power-down, as the output registers would otherwise hold stale data.
 
+impl<Bus, Config> Lis3dh<Bus, Config>
+where
+    Bus: Lis3dhBus,
+    Config: ValidLis3dhConfig,
+    Config::Odr: ctrl_reg1::odr::Active,
+{
     /// Reads and returns the acceleration values from `OUT_X_L (0x28)` to `OUT_Z_U (0x2D)`
     pub async fn read_accel_bytes(&mut self) -> Result<[u8; 6], Error<Bus::BusError>> {
         let mut result = [0; 6];
@@ -100,14 +979,1102 @@ where
         Ok(result)
     }
 
+    /// Reads `STATUS_REG (0x27)` through `OUT_Z_H (0x2D)` in a single burst, returning the status and the acceleration vector.
+    ///
+    /// This saves a transaction over [`Lis3dh::read_status`] followed by [`Lis3dh::get_accel_vector`] in tight sampling loops. The status is read before the output registers, so it describes the returned sample.
+    pub async fn read_status_and_accel(
+        &mut self,
+    ) -> Result<(DataStatus, AccelerationVector), Error<Bus::BusError>> {
+        let mut result = [0; 7];
+        self.bus
+            .read_multiple(ReadOnlyRegisterAddress::StatusReg, &mut result)
+            .await?;
+        let [status_reg, accel_bytes @ ..] = result;
+        Ok((
+            DataStatus::from(status_reg),
+            accel_bytes_into_vector::<Config>(accel_bytes),
+        ))
+    }
+
     /// Returns the resolution adjusted signed integer value from concatenated upper and lower bytes for each acceleration axis.
     pub async fn get_accel_vector(&mut self) -> Result<AccelerationVector, Error<Bus::BusError>> {
-        let [a_x_l, a_x_u, a_y_l, a_y_u, a_z_l, a_z_u] = self.read_accel_bytes().await?;
-        let x = Acceleration::new(Self::accel_raw_into_i16(a_x_l, a_x_u));
-        let y = Acceleration::new(Self::accel_raw_into_i16(a_y_l, a_y_u));
-        let z = Acceleration::new(Self::accel_raw_into_i16(a_z_l, a_z_u));
+        let accel_bytes = self.read_accel_bytes().await?;
+        Ok(accel_bytes_into_vector::<Config>(accel_bytes))
+    }
+
+    /// Returns the left-justified 16-bit content of each axis' output register pair as `[x, y, z]`, in the configured endianness but without the resolution shift applied by [`Lis3dh::get_accel_vector`].
+    ///
+    /// The unused low bits are left in place, so one count always equals 1/16 of a full-scale digit at 16-bit, regardless of low-power, normal or high-resolution mode, e.g. +1 g at ±2 g reads about 16384 in every mode. This suits DSP code doing its own scaling; [`Acceleration::value`] instead counts in digits of the configured resolution.
+    pub async fn read_accel_raw_u16(&mut self) -> Result<[i16; 3], Error<Bus::BusError>> {
+        let [a_x_0, a_x_1, a_y_0, a_y_1, a_z_0, a_z_1] = self.read_accel_bytes().await?;
+        Ok([
+            raw_into_i16::<Config>([a_x_0, a_x_1]),
+            raw_into_i16::<Config>([a_y_0, a_y_1]),
+            raw_into_i16::<Config>([a_z_0, a_z_1]),
+        ])
+    }
+
+    /// Like [`Lis3dh::get_accel_vector`], but only reads the output registers of the axes enabled in the config, returning 0 for disabled axes.
+    ///
+    /// The enabled axes are read in a single burst from the first to the last enabled axis. With only X and Z enabled the burst still spans `OUT_Y_L (0x2A)` and `OUT_Y_H (0x2B)`, which is cheaper than a second transaction.
+    pub async fn read_enabled_axes(&mut self) -> Result<AccelerationVector, Error<Bus::BusError>> {
+        const OUT_L: [ReadOnlyRegisterAddress; 3] = [
+            ReadOnlyRegisterAddress::OutXL,
+            ReadOnlyRegisterAddress::OutYL,
+            ReadOnlyRegisterAddress::OutZL,
+        ];
+
+        // `XYZDisabled` is only entitled to power-down, so at least one axis is enabled here.
+        let enabled_axes = <Config::AxisEnable as ctrl_reg1::axis_enable::State>::VARIANT as u8;
+        let first = enabled_axes.trailing_zeros() as usize;
+        let last = 7 - enabled_axes.leading_zeros() as usize;
+
+        let mut accel_bytes = [0; 6];
+        self.bus
+            .read_multiple(OUT_L[first], &mut accel_bytes[2 * first..2 * (last + 1)])
+            .await?;
+        let mut vector = accel_bytes_into_vector::<Config>(accel_bytes);
+        for (axis, acceleration) in [&mut vector.x, &mut vector.y, &mut vector.z]
+            .into_iter()
+            .enumerate()
+        {
+            if enabled_axes & (1 << axis) == 0 {
+                *acceleration = Acceleration::new(0);
+            }
+        }
+        Ok(vector)
+    }
+
+    /// Drains samples buffered in the FIFO into `out`, returning the number of samples written and whether the FIFO had overrun.
+    ///
+    /// The number of unread samples is read from `FIFO_SRC_REG`, then each sample is read with a burst from `OUT_X_L (0x28)` to `OUT_Z_H (0x2D)` which pops it from the FIFO. Reading stops once `out` is full or the FIFO is empty, so an empty FIFO returns a `sample_count` of 0.
+    /// An overrun FIFO is full (32 samples) and is drained like any other, but in stream mode the samples it overwrote are lost. Check [`FifoRead::overrun`] and clear the FIFO with [`Lis3dh::reset_fifo`] if the gap matters.
+    pub async fn read_fifo(
+        &mut self,
+        out: &mut [AccelerationVector],
+    ) -> Result<FifoRead, Error<Bus::BusError>> {
+        let fifo_status = self.read_fifo_status().await?;
+        let sample_count = (fifo_status.unread_samples() as usize).min(out.len());
+        for sample in &mut out[..sample_count] {
+            *sample = self.get_accel_vector().await?;
+        }
+        Ok(FifoRead {
+            sample_count,
+            overrun: fifo_status.overrun,
+        })
+    }
+
+    /// Returns a [`FifoIter`] that drains the FIFO one sample per [`FifoIter::next`], so samples can be processed and discarded without a buffer.
+    ///
+    /// Each step checks `EMPTY` in `FIFO_SRC_REG` before reading a sample, so samples that arrive while iterating are included. In stream mode at output data rates faster than the caller consumes samples the FIFO may never empty, so bound the iteration if that matters.
+    pub fn fifo_iter(&mut self) -> FifoIter<'_, Bus, Config> {
+        FifoIter { lis3dh: self }
+    }
+
+    /// Drains up to `N` samples from the FIFO like [`Lis3dh::read_fifo`], into an array on the stack. Returns the array and the [`FifoRead`] result; entries past [`FifoRead::sample_count`] are [`ZERO_ACCELERATION_VECTOR`].
+    pub async fn read_fifo_array<const N: usize>(
+        &mut self,
+    ) -> Result<([AccelerationVector; N], FifoRead), Error<Bus::BusError>> {
+        let mut samples = [ZERO_ACCELERATION_VECTOR; N];
+        let fifo_read = self.read_fifo(&mut samples).await?;
+        Ok((samples, fifo_read))
+    }
+
+    /// Returns each acceleration axis `[x, y, z]` in units of gravity as Q(31 - FRAC).FRAC fixed-point, computed without any floating point math.
+    ///
+    /// See [`Acceleration::as_g_qformat`] for the supported `FRAC` range and saturation behaviour.
+    pub async fn get_accel_qformat<const FRAC: u32>(
+        &mut self,
+    ) -> Result<[i32; 3], Error<Bus::BusError>> {
+        let AccelerationVector { x, y, z } = self.get_accel_vector().await?;
+        Ok([x, y, z].map(|a| a.as_g_qformat::<Config::GravityCoefficient, FRAC>()))
+    }
+
+    /// Reads the acceleration vector and returns its tilt angles in degrees, see [`AccelerationVector::tilt_angles_deg`] for the axis convention.
+    #[cfg(feature = "libm")]
+    pub async fn get_tilt_angles(
+        &mut self,
+    ) -> Result<acceleration_data_structs::TiltAngles, Error<Bus::BusError>> {
+        Ok(self
+            .get_accel_vector()
+            .await?
+            .tilt_angles_deg::<Config::GravityCoefficient>())
+    }
+
+    /// Polls `STATUS_REG` until new data is available on all axes (`ZYXDA`), then returns a fresh acceleration vector.
+    ///
+    /// Between polls it sleeps a quarter of the output data rate period, so a new sample is picked up shortly after it's produced without flooding the bus. Returns [`Error::Timeout`] if no sample is ready after `max_retries` sleeps, e.g. when the lis3dh was powered down through a raw register write.
+    pub async fn get_accel_vector_when_ready<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        max_retries: u8,
+    ) -> Result<AccelerationVector, Error<Bus::BusError>> {
+        let poll_interval_us = Self::data_ready_poll_interval_us();
+
+        let mut retries = 0;
+        while !self.read_status().await?.zyx_ready {
+            if retries == max_retries {
+                return Err(Error::Timeout);
+            }
+            retries += 1;
+            delay.delay_us(poll_interval_us).await;
+        }
+        self.get_accel_vector().await
+    }
+
+    /// Interval between `STATUS_REG` polls while waiting for new data: a quarter of the output data rate period.
+    fn data_ready_poll_interval_us() -> u32 {
+        let frequency_hz = <Config::OdrHz as odr_hz::Property>::FREQUENCY_HZ;
+        (1_000_000.0 / (4.0 * frequency_hz)) as u32
+    }
+
+    /// Turns the driver into an endless stream yielding one acceleration vector per data-ready event.
+    ///
+    /// `STATUS_REG` is polled like [`Lis3dh::get_accel_vector_when_ready`], but without a retry limit. A sample is read when new data is available or has overrun, so falling behind the output data rate loses samples rather than stalling the stream. Bus errors are yielded as `Err` items and the stream carries on polling afterwards.
+    #[cfg(feature = "stream")]
+    pub fn into_sample_stream<D: DelayNs>(
+        self,
+        delay: D,
+    ) -> impl futures_util::Stream<Item = Result<AccelerationVector, Error<Bus::BusError>>> {
+        futures_util::stream::unfold((self, delay), |(mut lis3dh, mut delay)| async move {
+            let sample = lis3dh.next_sample(&mut delay).await;
+            Some((sample, (lis3dh, delay)))
+        })
+    }
+
+    /// Waits for the next sample for [`Lis3dh::into_sample_stream`].
+    #[cfg(feature = "stream")]
+    async fn next_sample<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<AccelerationVector, Error<Bus::BusError>> {
+        loop {
+            let status = self.read_status().await?;
+            if status.zyx_ready || status.zyx_overrun {
+                return self.get_accel_vector().await;
+            }
+            delay.delay_us(Self::data_ready_poll_interval_us()).await;
+        }
+    }
+
+    /// Configures interrupt 1 to wake on motion: an OR combination of high events on every axis, i.e. any axis exceeding `threshold_mg` once the high-pass filter has removed gravity.
+    ///
+    /// The high-pass filter is enabled for interrupt 1 with `HP_IA1` in `CTRL_REG2 (0x21)` and reset by reading `REFERENCE (0x26)`, interrupt 1 is latched, and `I1_IA1` in `CTRL_REG3 (0x22)` routes it to the INT1 pin, keeping the other routed sources. The threshold is converted like in [`Lis3dh::configure_free_fall`].
+    pub async fn configure_wake_on_motion(
+        &mut self,
+        threshold_mg: u16,
+    ) -> Result<(), Error<Bus::BusError>> {
+        const WAKE_ON_MOTION_INT1_CFG: u8 =
+            1 << int1_cfg::ZHIE_OFFSET | 1 << int1_cfg::YHIE_OFFSET | 1 << int1_cfg::XHIE_OFFSET;
+
+        let threshold =
+            threshold_coefficient::milli_g_to_counts::<Config::ThresholdCoefficient>(threshold_mg);
+
+        self.modify_field(
+            ReadWriteRegisterAddress::CtrlReg2,
+            ctrl_reg2::hp_ia1::OFFSET,
+            ctrl_reg2::hp_ia1::WIDTH,
+            1,
+        )
+        .await?;
+        // SAFETY: Starting memory address `Int1Ths = 0x32` incremented once leads to `Int1Duration = 0x33` which are both writable memory addresses.
+        unsafe {
+            self.bus
+                .write_multiple(ReadWriteRegisterAddress::Int1Ths, &[threshold, 0])
+                .await?
+        };
+        self.read_reference().await?;
+        self.bus
+            .write(ReadWriteRegisterAddress::Int1Cfg, WAKE_ON_MOTION_INT1_CFG)
+            .await?;
+        self.set_int1_latched(true).await?;
+        self.modify_field(
+            ReadWriteRegisterAddress::CtrlReg3,
+            ctrl_reg3::i1_ia1::OFFSET,
+            ctrl_reg3::i1_ia1::WIDTH,
+            1,
+        )
+        .await
+    }
+
+    /// Configures wake-on-motion with [`Lis3dh::configure_wake_on_motion`], then polls `INT1_SRC (0x31)` once per output data rate period until motion is detected, returning the source that cleared the latch.
+    ///
+    /// Polling keeps the MCU awake and issues a bus transaction every output data rate period, which at high data rates costs far more current than the lis3dh itself. On battery power, prefer [`Lis3dh::wait_for_motion_on_pin`], which lets the MCU sleep until INT1 asserts, or lower the output data rate first.
+    pub async fn wait_for_motion<D: DelayNs>(
+        &mut self,
+        threshold_mg: u16,
+        delay: &mut D,
+    ) -> Result<InterruptSource, Error<Bus::BusError>> {
+        self.configure_wake_on_motion(threshold_mg).await?;
+        loop {
+            let source = self.read_int1_src().await?;
+            if source.active {
+                return Ok(source);
+            }
+            delay
+                .delay_us(4 * Self::data_ready_poll_interval_us())
+                .await;
+        }
+    }
+
+    /// Configures wake-on-motion with [`Lis3dh::configure_wake_on_motion`], then waits for `pin`, wired to INT1, like [`Lis3dh::wait_for_interrupt`], returning the source that cleared the latch.
+    ///
+    /// Unlike [`Lis3dh::wait_for_motion`] there is no bus traffic while waiting, so the MCU can sleep until the lis3dh detects motion.
+    pub async fn wait_for_motion_on_pin<P: digital::Wait>(
+        &mut self,
+        threshold_mg: u16,
+        pin: &mut P,
+    ) -> Result<InterruptSource, Error<Bus::BusError>> {
+        self.configure_wake_on_motion(threshold_mg).await?;
+        self.wait_for_interrupt(pin).await
+    }
+
+    /// Turns the driver into an endless stream of [`Event`]s by racing `int1_pin` and `int2_pin`, for designs routing data-ready to INT1 and motion to INT2.
+    ///
+    /// Route `I1_ZYXDA` to INT1 with [`Lis3dh::configure_int1_routing`] and interrupt generator 2 to INT2 (`I2_IA2` in `CTRL_REG6`), and latch interrupt 2 (`LIR_INT2` in `CTRL_REG5`) so a short motion event isn't missed while a sample is read. INT1 is serviced by reading the acceleration vector and INT2 by reading `INT2_SRC`, which releases the pin that fired.
+    ///
+    /// Both pins are awaited at their active level, which follows `INT_POLARITY` in `CTRL_REG6 (0x25)` and is read once before the first event. When both pins are asserted at once the data-ready event is yielded first; the latched INT2 stays asserted, so the motion event follows on the next poll. A pin wait failing is yielded as [`Error::InterruptPin`] and bus errors as [`Error::Bus`], and the stream carries on afterwards.
+    #[cfg(feature = "stream")]
+    pub fn event_stream<P1, P2>(
+        self,
+        int1_pin: P1,
+        int2_pin: P2,
+    ) -> impl futures_util::Stream<Item = Result<Event, Error<Bus::BusError>>>
+    where
+        P1: digital::Wait,
+        P2: digital::Wait,
+    {
+        futures_util::stream::unfold(
+            (self, int1_pin, int2_pin, None),
+            |(mut lis3dh, mut int1_pin, mut int2_pin, mut active_low)| async move {
+                let event = lis3dh
+                    .next_event(&mut int1_pin, &mut int2_pin, &mut active_low)
+                    .await;
+                Some((event, (lis3dh, int1_pin, int2_pin, active_low)))
+            },
+        )
+    }
+
+    /// Waits for the next event for [`Lis3dh::event_stream`], reading the interrupt polarity into `active_low` first if it isn't known yet.
+    #[cfg(feature = "stream")]
+    async fn next_event<P1: digital::Wait, P2: digital::Wait>(
+        &mut self,
+        int1_pin: &mut P1,
+        int2_pin: &mut P2,
+        active_low: &mut Option<bool>,
+    ) -> Result<Event, Error<Bus::BusError>> {
+        use futures_util::future::{select, Either};
+
+        let active_low = match *active_low {
+            Some(active_low) => active_low,
+            None => {
+                let ctrl_reg6 = self.bus.read(ReadWriteRegisterAddress::CtrlReg6).await?;
+                *active_low.insert(ctrl_reg6 & (1 << ctrl_reg6::int_polarity::OFFSET) != 0)
+            }
+        };
+
+        let int1 = core::pin::pin!(wait_for_active_level(int1_pin, active_low));
+        let int2 = core::pin::pin!(wait_for_active_level(int2_pin, active_low));
+        match select(int1, int2).await {
+            Either::Left((asserted, _)) => {
+                asserted.map_err(|_| Error::InterruptPin)?;
+                Ok(Event::DataReady(self.get_accel_vector().await?))
+            }
+            Either::Right((asserted, _)) => {
+                asserted.map_err(|_| Error::InterruptPin)?;
+                Ok(Event::Motion(self.read_int2_src().await?))
+            }
+        }
+    }
+
+    /// Runs the self-test and checks each axis' output change against the datasheet window.
+    ///
+    /// The average of 5 samples is recorded, self-test 0 is enabled in `CTRL_REG4`, and after waiting for the output to settle, the average of 5 samples is recorded again. The first sample after each settling is discarded. `CTRL_REG4` is restored afterwards, even if reading a sample failed.
+    /// The window is specified as 17 to 360 digits in normal mode and scaled to milli-g with the configured full scale, e.g. 68 to 1440 mg at ±2 g. The lis3dh must be kept still during the test.
+    pub async fn run_self_test<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<SelfTestResult, Error<Bus::BusError>> {
+        let delta_mg = self.self_test_delta_mg(delay).await?;
+        let window_mg = Self::self_test_window_mg();
+        let axis_passed = delta_mg.map(|delta| window_mg.contains(&delta));
+        Ok(SelfTestResult {
+            delta_mg,
+            axis_passed,
+            passed: axis_passed.iter().all(|&passed| passed),
+        })
+    }
+
+    /// Runs the self-test like [`Lis3dh::run_self_test`] once per axis, with only that axis enabled in `CTRL_REG1`, to isolate a failing axis.
+    ///
+    /// `CTRL_REG1` is restored afterwards, even if a test failed. Each axis is a full self-test, so this takes three times as long as [`Lis3dh::run_self_test`]: 540 ms of settling plus 36 samples at the output data rate.
+    pub async fn run_self_test_per_axis<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<[AxisSelfTest; 3], Error<Bus::BusError>> {
+        use ctrl_reg1::axis_enable;
+
+        const AXIS_ENABLE_MASK: u8 = ((1 << axis_enable::WIDTH) - 1) << axis_enable::OFFSET;
+
+        let ctrl_reg1 = self.bus.read(ReadWriteRegisterAddress::CtrlReg1).await?;
+        let mut results = [AxisSelfTest {
+            delta_mg: 0,
+            passed: false,
+        }; 3];
+        for (axis, enable) in [
+            axis_enable::Variant::XEnabled,
+            axis_enable::Variant::YEnabled,
+            axis_enable::Variant::ZEnabled,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let result = self
+                .self_test_on_axes(
+                    ctrl_reg1 & !AXIS_ENABLE_MASK | (enable as u8) << axis_enable::OFFSET,
+                    delay,
+                )
+                .await;
+            let delta_mg = match result {
+                Ok(delta_mg) => delta_mg[axis],
+                Err(error) => {
+                    self.bus
+                        .write(ReadWriteRegisterAddress::CtrlReg1, ctrl_reg1)
+                        .await?;
+                    return Err(error);
+                }
+            };
+            results[axis] = AxisSelfTest {
+                delta_mg,
+                passed: Self::self_test_window_mg().contains(&delta_mg),
+            };
+        }
+        self.bus
+            .write(ReadWriteRegisterAddress::CtrlReg1, ctrl_reg1)
+            .await?;
+        Ok(results)
+    }
+
+    /// Self-test output change window in milli-g, i.e. the datasheet window in normal mode digits scaled with the milli-g/digit of the configured full scale in normal mode.
+    fn self_test_window_mg() -> core::ops::RangeInclusive<i32> {
+        use gravity_coefficient::Property;
+
+        let milli_g_per_digit = i32::from(
+            gravity_coefficient::GravityCoefficient::<
+                Config::Fs,
+                resolution::Resolution<
+                    ctrl_reg1::lp_en::NormalPowerMode,
+                    ctrl_reg4::hr::NormalResolution,
+                >,
+            >::MILLI_G_PER_DIGIT,
+        );
+        SELF_TEST_MIN_LSB * milli_g_per_digit..=SELF_TEST_MAX_LSB * milli_g_per_digit
+    }
+
+    /// Writes `ctrl_reg1` to select the enabled axes, then returns the self-test output change of [`Lis3dh::self_test_delta_mg`].
+    async fn self_test_on_axes<D: DelayNs>(
+        &mut self,
+        ctrl_reg1: u8,
+        delay: &mut D,
+    ) -> Result<[i32; 3], Error<Bus::BusError>> {
+        self.bus
+            .write(ReadWriteRegisterAddress::CtrlReg1, ctrl_reg1)
+            .await?;
+        self.self_test_delta_mg(delay).await
+    }
+
+    /// Returns the absolute change of the averaged `[x, y, z]` output caused by self-test 0, in milli-g, restoring `CTRL_REG4` afterwards.
+    async fn self_test_delta_mg<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<[i32; 3], Error<Bus::BusError>> {
+        use ctrl_reg4::st;
+
+        const ST_MASK: u8 = ((1 << st::WIDTH) - 1) << st::OFFSET;
+
+        delay.delay_ms(SELF_TEST_SETTLING_TIME_MS).await;
+        let baseline = self.average_accel_vector(delay).await?;
+
+        let ctrl_reg4 = self.bus.read(ReadWriteRegisterAddress::CtrlReg4).await?;
+        self.bus
+            .write(
+                ReadWriteRegisterAddress::CtrlReg4,
+                ctrl_reg4 & !ST_MASK | (st::Variant::SelfTest0 as u8) << st::OFFSET,
+            )
+            .await?;
+        delay.delay_ms(SELF_TEST_SETTLING_TIME_MS).await;
+        let self_test = self.average_accel_vector(delay).await;
+
+        self.bus
+            .write(ReadWriteRegisterAddress::CtrlReg4, ctrl_reg4)
+            .await?;
+        let self_test = self_test?;
+
+        let milli_g_per_digit =
+            <Config::GravityCoefficient as gravity_coefficient::Property>::MILLI_G_PER_DIGIT as i32;
+        Ok([0, 1, 2].map(|axis| (self_test[axis] - baseline[axis]).abs() * milli_g_per_digit))
+    }
+
+    /// Discards the next sample, then returns the per-axis `[x, y, z]` average of the following [`SELF_TEST_SAMPLES`] samples.
+    async fn average_accel_vector<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<[i32; 3], Error<Bus::BusError>> {
+        self.get_accel_vector_when_ready(delay, u8::MAX).await?;
+        let average = self.read_accel_averaged(SELF_TEST_SAMPLES, delay).await?;
+        Ok(average.axes().map(|axis| axis.value as i32))
+    }
+
+    /// Reads `n` consecutive samples, waiting for data-ready before each like [`Lis3dh::get_accel_vector_when_ready`], and returns their component-wise mean rounded toward zero.
+    ///
+    /// The samples are summed in `i32`, so no `n` can overflow the accumulators. Returns [`Error::InvalidArgument`] if `n` is zero.
+    pub async fn read_accel_averaged<D: DelayNs>(
+        &mut self,
+        n: u8,
+        delay: &mut D,
+    ) -> Result<AccelerationVector, Error<Bus::BusError>> {
+        if n == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut sum = [0i32; 3];
+        for _ in 0..n {
+            let sample = self.get_accel_vector_when_ready(delay, u8::MAX).await?;
+            for (total, axis) in sum.iter_mut().zip(sample) {
+                *total += axis.value as i32;
+            }
+        }
+        let [x, y, z] = sum.map(|total| Acceleration::new((total / n as i32) as i16));
         Ok(AccelerationVector { x, y, z })
     }
+
+    /// Reads `n` consecutive samples like [`Lis3dh::read_accel_averaged`] and returns the per-axis `[x, y, z]` sample standard deviation in units of gravity, i.e. the noise floor.
+    ///
+    /// The lis3dh must be kept still during the measurement, as any motion is counted as noise. The mean and variance are accumulated on the fly, so `n` isn't limited by memory; use at least 32 samples for a meaningful estimate, as fewer make the result itself noisy. Returns [`Error::InvalidArgument`] if `n` is less than 2.
+    #[cfg(feature = "libm")]
+    pub async fn measure_noise_floor<D: DelayNs>(
+        &mut self,
+        n: u16,
+        delay: &mut D,
+    ) -> Result<[f32; 3], Error<Bus::BusError>> {
+        if n < 2 {
+            return Err(Error::InvalidArgument);
+        }
+
+        // Welford's algorithm, which avoids the cancellation of summing squares in `f32`.
+        let mut mean = [0.0f32; 3];
+        let mut squared_deviations = [0.0f32; 3];
+        for count in 1..=n {
+            let sample = self.get_accel_vector_when_ready(delay, u8::MAX).await?;
+            let axes = sample
+                .axes()
+                .map(|axis| axis.as_g::<Config::GravityCoefficient>());
+            for axis in 0..3 {
+                let deviation = axes[axis] - mean[axis];
+                mean[axis] += deviation / count as f32;
+                squared_deviations[axis] += deviation * (axes[axis] - mean[axis]);
+            }
+        }
+        Ok(squared_deviations.map(|sum| libm::sqrtf(sum / (n - 1) as f32)))
+    }
+
+    /// Reads the acceleration vector like [`Lis3dh::get_accel_vector`], retrying up to `max_retries` times with a short backoff if the read fails with [`Error::Bus`].
+    ///
+    /// Each retry adds the backoff delay on top of the read itself, so a persistently failing bus takes `max_retries` backoff periods longer to report its error.
+    pub async fn read_accel_vector_with_retry<D: DelayNs>(
+        &mut self,
+        max_retries: u8,
+        delay: &mut D,
+    ) -> Result<AccelerationVector, Error<Bus::BusError>> {
+        let mut retries = 0;
+        loop {
+            match self.get_accel_vector().await {
+                Err(Error::Bus(_)) if retries < max_retries => {
+                    retries += 1;
+                    delay.delay_us(RETRY_BACKOFF_US).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+// Partial reconfiguration commands.
+
+impl<Bus, Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth>
+    Lis3dh<
+        Bus,
+        config::Config<Odr, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth>,
+    >
+where
+    Bus: Lis3dhBus,
+    Odr: ctrl_reg1::odr::State + Entitled<LpEn>,
+    LpEn: ctrl_reg1::lp_en::State,
+    AxisEnable: ctrl_reg1::axis_enable::State + Entitled<Odr>,
+    Fs: ctrl_reg4::fs::State,
+    Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+    Bdu: ctrl_reg4::bdu::State,
+    Ble: ctrl_reg4::ble::State + Entitled<Hr>,
+    AdcEn: temp_cfg_reg::adc_en::State,
+    TempEn: temp_cfg_reg::temp_en::State + Entitled<AdcEn>,
+    FifoEn: ctrl_reg5::fifo_en::State,
+    Fm: fifo_ctrl_reg::fm::State + Entitled<FifoEn>,
+    Fth: fifo_ctrl_reg::fth::State,
+{
+    /// Changes the output data rate by read-modify-writing only the `odr` field of `CTRL_REG1`, unlike [`Lis3dh::reconfigure`] which re-writes every register.
+    pub async fn set_data_rate<NewOdr>(
+        mut self,
+        data_rate: NewOdr,
+    ) -> Result<
+        Lis3dh<
+            Bus,
+            config::Config<
+                NewOdr,
+                LpEn,
+                AxisEnable,
+                Fs,
+                Hr,
+                Bdu,
+                Ble,
+                AdcEn,
+                TempEn,
+                FifoEn,
+                Fm,
+                Fth,
+            >,
+        >,
+        Error<Bus::BusError>,
+    >
+    where
+        NewOdr: ctrl_reg1::odr::State + Entitled<LpEn>,
+        AxisEnable: Entitled<NewOdr>,
+    {
+        use ctrl_reg1::odr;
+
+        self.modify_field(
+            ReadWriteRegisterAddress::CtrlReg1,
+            odr::OFFSET,
+            odr::WIDTH,
+            NewOdr::VARIANT as u8,
+        )
+        .await?;
+
+        let Lis3dh { bus, config } = self;
+        Ok(Lis3dh {
+            bus,
+            config: config::Config {
+                data_rate,
+                power_mode: config.power_mode,
+                axis_enable: config.axis_enable,
+                full_scale: config.full_scale,
+                resolution_mode: config.resolution_mode,
+                block_data_update: config.block_data_update,
+                endianness: config.endianness,
+                adc_enable: config.adc_enable,
+                temperature_enable: config.temperature_enable,
+                fifo_enable: config.fifo_enable,
+                fifo_mode: config.fifo_mode,
+                fifo_watermark: config.fifo_watermark,
+            },
+        })
+    }
+
+    /// Stops conversions for duty-cycling by setting the `odr` field of `CTRL_REG1` to power-down, leaving every other register untouched. Resume with [`Lis3dh::wake`].
+    ///
+    /// Acceleration commands aren't available while asleep, see [`ctrl_reg1::odr::Active`].
+    pub async fn sleep(
+        self,
+    ) -> Result<
+        Lis3dh<
+            Bus,
+            config::Config<
+                ctrl_reg1::odr::PowerDown,
+                LpEn,
+                AxisEnable,
+                Fs,
+                Hr,
+                Bdu,
+                Ble,
+                AdcEn,
+                TempEn,
+                FifoEn,
+                Fm,
+                Fth,
+            >,
+        >,
+        Error<Bus::BusError>,
+    >
+    where
+        AxisEnable: Entitled<ctrl_reg1::odr::PowerDown>,
+    {
+        self.set_data_rate(ctrl_reg1::odr::PowerDown).await
+    }
+
+    /// Changes the full scale by read-modify-writing only the `fs` field of `CTRL_REG4`, unlike [`Lis3dh::reconfigure`] which re-writes every register.
+    pub async fn set_full_scale<NewFs>(
+        mut self,
+        full_scale: NewFs,
+    ) -> Result<
+        Lis3dh<
+            Bus,
+            config::Config<
+                Odr,
+                LpEn,
+                AxisEnable,
+                NewFs,
+                Hr,
+                Bdu,
+                Ble,
+                AdcEn,
+                TempEn,
+                FifoEn,
+                Fm,
+                Fth,
+            >,
+        >,
+        Error<Bus::BusError>,
+    >
+    where
+        NewFs: ctrl_reg4::fs::State,
+    {
+        use ctrl_reg4::fs;
+
+        self.modify_field(
+            ReadWriteRegisterAddress::CtrlReg4,
+            fs::OFFSET,
+            fs::WIDTH,
+            NewFs::VARIANT as u8,
+        )
+        .await?;
+
+        let Lis3dh { bus, config } = self;
+        Ok(Lis3dh {
+            bus,
+            config: config::Config {
+                data_rate: config.data_rate,
+                power_mode: config.power_mode,
+                axis_enable: config.axis_enable,
+                full_scale,
+                resolution_mode: config.resolution_mode,
+                block_data_update: config.block_data_update,
+                endianness: config.endianness,
+                adc_enable: config.adc_enable,
+                temperature_enable: config.temperature_enable,
+                fifo_enable: config.fifo_enable,
+                fifo_mode: config.fifo_mode,
+                fifo_watermark: config.fifo_watermark,
+            },
+        })
+    }
+
+    /// Switches to low power mode (8-bit) by read-modify-writing the `lp_en` field of `CTRL_REG1`.
+    ///
+    /// Low power mode can't be combined with high resolution, so a high resolution config first has the `hr` field of `CTRL_REG4` cleared. Output data rates exclusive to normal power mode and big endian output (which requires high resolution) can't enter low power mode.
+    pub async fn enter_low_power(
+        mut self,
+    ) -> Result<
+        Lis3dh<
+            Bus,
+            config::Config<
+                Odr,
+                ctrl_reg1::lp_en::LowPowerMode,
+                AxisEnable,
+                Fs,
+                ctrl_reg4::hr::NormalResolution,
+                Bdu,
+                Ble,
+                AdcEn,
+                TempEn,
+                FifoEn,
+                Fm,
+                Fth,
+            >,
+        >,
+        Error<Bus::BusError>,
+    >
+    where
+        Odr: Entitled<ctrl_reg1::lp_en::LowPowerMode>,
+        Ble: Entitled<ctrl_reg4::hr::NormalResolution>,
+    {
+        use ctrl_reg1::lp_en;
+        use ctrl_reg4::hr;
+
+        if matches!(Hr::VARIANT, hr::Variant::HighResolution) {
+            self.modify_field(
+                ReadWriteRegisterAddress::CtrlReg4,
+                hr::OFFSET,
+                hr::WIDTH,
+                hr::Variant::NormalResolution as u8,
+            )
+            .await?;
+        }
+        self.modify_field(
+            ReadWriteRegisterAddress::CtrlReg1,
+            lp_en::OFFSET,
+            lp_en::WIDTH,
+            lp_en::Variant::LowPowerMode as u8,
+        )
+        .await?;
+
+        let Lis3dh { bus, config } = self;
+        Ok(Lis3dh {
+            bus,
+            config: config::Config {
+                data_rate: config.data_rate,
+                power_mode: lp_en::LowPowerMode,
+                axis_enable: config.axis_enable,
+                full_scale: config.full_scale,
+                resolution_mode: hr::NormalResolution,
+                block_data_update: config.block_data_update,
+                endianness: config.endianness,
+                adc_enable: config.adc_enable,
+                temperature_enable: config.temperature_enable,
+                fifo_enable: config.fifo_enable,
+                fifo_mode: config.fifo_mode,
+                fifo_watermark: config.fifo_watermark,
+            },
+        })
+    }
+
+    /// Switches to normal power mode (10-bit, or 12-bit with high resolution) by read-modify-writing only the `lp_en` field of `CTRL_REG1`.
+    ///
+    /// Output data rates exclusive to low power mode can't enter normal power mode; change the data rate first with [`Lis3dh::set_data_rate`].
+    pub async fn enter_normal_power(
+        mut self,
+    ) -> Result<
+        Lis3dh<
+            Bus,
+            config::Config<
+                Odr,
+                ctrl_reg1::lp_en::NormalPowerMode,
+                AxisEnable,
+                Fs,
+                Hr,
+                Bdu,
+                Ble,
+                AdcEn,
+                TempEn,
+                FifoEn,
+                Fm,
+                Fth,
+            >,
+        >,
+        Error<Bus::BusError>,
+    >
+    where
+        Odr: Entitled<ctrl_reg1::lp_en::NormalPowerMode>,
+        Hr: Entitled<ctrl_reg1::lp_en::NormalPowerMode>,
+    {
+        use ctrl_reg1::lp_en;
+
+        self.modify_field(
+            ReadWriteRegisterAddress::CtrlReg1,
+            lp_en::OFFSET,
+            lp_en::WIDTH,
+            lp_en::Variant::NormalPowerMode as u8,
+        )
+        .await?;
+
+        let Lis3dh { bus, config } = self;
+        Ok(Lis3dh {
+            bus,
+            config: config::Config {
+                data_rate: config.data_rate,
+                power_mode: lp_en::NormalPowerMode,
+                axis_enable: config.axis_enable,
+                full_scale: config.full_scale,
+                resolution_mode: config.resolution_mode,
+                block_data_update: config.block_data_update,
+                endianness: config.endianness,
+                adc_enable: config.adc_enable,
+                temperature_enable: config.temperature_enable,
+                fifo_enable: config.fifo_enable,
+                fifo_mode: config.fifo_mode,
+                fifo_watermark: config.fifo_watermark,
+            },
+        })
+    }
+
+    /// Switches the operating mode, i.e. the entangled output data rate, power mode and resolution, to `mode`: low power (8-bit), normal (10-bit) or high resolution (12-bit), e.g. `OperatingMode { data_rate: F100Hz, power_mode: LowPowerMode, resolution_mode: NormalResolution }`.
+    ///
+    /// The adjacent `odr` and `lp_en` fields of `CTRL_REG1` are read-modify-written together, and the `hr` field of `CTRL_REG4` separately, ordered so the forbidden combination of low power and high resolution is never set in between: `hr` is written first when entering low power mode and `CTRL_REG1` first otherwise. An [`properties::operating_mode::OperatingMode`] can only be built from entitled combinations, so an invalid mode fails to compile. The returned driver's output data rate, resolution and gravity coefficient follow the new mode. Big endian output (which requires high resolution) and disabled axes (which require power-down) restrict the modes that can be selected.
+    pub async fn set_operating_mode<M>(
+        mut self,
+        mode: M,
+    ) -> Result<
+        Lis3dh<
+            Bus,
+            config::Config<
+                M::Odr,
+                M::LpEn,
+                AxisEnable,
+                Fs,
+                M::Hr,
+                Bdu,
+                Ble,
+                AdcEn,
+                TempEn,
+                FifoEn,
+                Fm,
+                Fth,
+            >,
+        >,
+        Error<Bus::BusError>,
+    >
+    where
+        M: operating_mode::Property,
+        AxisEnable: Entitled<M::Odr>,
+        Ble: Entitled<M::Hr>,
+    {
+        use ctrl_reg1::{lp_en, odr};
+        use ctrl_reg4::hr;
+
+        // `odr` sits right above `lp_en`, so both are replaced as one field.
+        let ctrl_reg1_fields = (
+            ReadWriteRegisterAddress::CtrlReg1,
+            lp_en::OFFSET,
+            lp_en::WIDTH + odr::WIDTH,
+            (<M::Odr as odr::State>::VARIANT as u8) << (odr::OFFSET - lp_en::OFFSET)
+                | <M::LpEn as lp_en::State>::VARIANT as u8,
+        );
+        let hr_field = (
+            ReadWriteRegisterAddress::CtrlReg4,
+            hr::OFFSET,
+            hr::WIDTH,
+            <M::Hr as hr::State>::VARIANT as u8,
+        );
+        let fields = if matches!(
+            <M::LpEn as lp_en::State>::VARIANT,
+            lp_en::Variant::LowPowerMode
+        ) {
+            [hr_field, ctrl_reg1_fields]
+        } else {
+            [ctrl_reg1_fields, hr_field]
+        };
+        for (register_address, offset, width, value) in fields {
+            self.modify_field(register_address, offset, width, value)
+                .await?;
+        }
+
+        let (data_rate, power_mode, resolution_mode) = mode.into_states();
+        let Lis3dh { bus, config } = self;
+        Ok(Lis3dh {
+            bus,
+            config: config::Config {
+                data_rate,
+                power_mode,
+                axis_enable: config.axis_enable,
+                full_scale: config.full_scale,
+                resolution_mode,
+                block_data_update: config.block_data_update,
+                endianness: config.endianness,
+                adc_enable: config.adc_enable,
+                temperature_enable: config.temperature_enable,
+                fifo_enable: config.fifo_enable,
+                fifo_mode: config.fifo_mode,
+                fifo_watermark: config.fifo_watermark,
+            },
+        })
+    }
+}
+
+// Wake commands.
+// Only available when asleep, i.e. with an output data rate of power-down.
+
+impl<Bus, LpEn, AxisEnable, Fs, Hr, Bdu, Ble, AdcEn, TempEn, FifoEn, Fm, Fth>
+    Lis3dh<
+        Bus,
+        config::Config<
+            ctrl_reg1::odr::PowerDown,
+            LpEn,
+            AxisEnable,
+            Fs,
+            Hr,
+            Bdu,
+            Ble,
+            AdcEn,
+            TempEn,
+            FifoEn,
+            Fm,
+            Fth,
+        >,
+    >
+where
+    Bus: Lis3dhBus,
+    LpEn: ctrl_reg1::lp_en::State,
+    AxisEnable: ctrl_reg1::axis_enable::State + Entitled<ctrl_reg1::odr::PowerDown>,
+    Fs: ctrl_reg4::fs::State,
+    Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+    Bdu: ctrl_reg4::bdu::State,
+    Ble: ctrl_reg4::ble::State + Entitled<Hr>,
+    AdcEn: temp_cfg_reg::adc_en::State,
+    TempEn: temp_cfg_reg::temp_en::State + Entitled<AdcEn>,
+    FifoEn: ctrl_reg5::fifo_en::State,
+    Fm: fifo_ctrl_reg::fm::State + Entitled<FifoEn>,
+    Fth: fifo_ctrl_reg::fth::State,
+{
+    /// Resumes conversions after [`Lis3dh::sleep`] by restoring the `odr` field of `CTRL_REG1` to `data_rate`, leaving every other register untouched.
+    ///
+    /// The first samples after waking are subject to the turn-on time, see [`Lis3dh::new_with_delay`].
+    pub async fn wake<NewOdr>(
+        self,
+        data_rate: NewOdr,
+    ) -> Result<
+        Lis3dh<
+            Bus,
+            config::Config<
+                NewOdr,
+                LpEn,
+                AxisEnable,
+                Fs,
+                Hr,
+                Bdu,
+                Ble,
+                AdcEn,
+                TempEn,
+                FifoEn,
+                Fm,
+                Fth,
+            >,
+        >,
+        Error<Bus::BusError>,
+    >
+    where
+        NewOdr: ctrl_reg1::odr::Active + Entitled<LpEn>,
+        AxisEnable: Entitled<NewOdr>,
+    {
+        self.set_data_rate(data_rate).await
+    }
+}
+
+// FIFO commands.
+
+impl<Bus, Config> Lis3dh<Bus, Config>
+where
+    Bus: Lis3dhBus,
+    Config: ValidLis3dhConfig<FifoEn = ctrl_reg5::fifo_en::FifoEnabled>,
+{
+    /// Switches the FIFO to stream-to-FIFO mode by writing `FIFO_CTRL_REG (0x2E)`, to capture a window of samples around an event.
+    ///
+    /// The FIFO runs in stream mode, continuously overwriting its oldest samples, until the interrupt generator selected by `trigger` raises its event. It then switches to FIFO mode and stops once full, so it holds the samples leading up to the event followed by those after it. The selected generator must be configured separately, e.g. with [`Lis3dh::configure_free_fall`] for [`FifoTrigger::Int1`]. Pull the captured window with [`Lis3dh::read_fifo`] once the event fires.
+    ///
+    /// `watermark` is the `FTH` level in `0..=31`; anything larger returns [`Error::InvalidArgument`] without bus traffic.
+    /// The mode isn't part of `Config`, so [`Lis3dh::reset_fifo`] and [`Lis3dh::reconfigure`] restore the configured FIFO mode and [`Lis3dh::verify_config`] reports a mismatch while it is active.
+    pub async fn configure_stream_to_fifo(
+        &mut self,
+        watermark: u8,
+        trigger: FifoTrigger,
+    ) -> Result<(), Error<Bus::BusError>> {
+        use fifo_ctrl_reg::{fm, fth, tr};
+
+        if watermark >= 1 << fth::WIDTH {
+            return Err(Error::InvalidArgument);
+        }
+        let fifo_ctrl_reg = (fm::Variant::StreamToFifo as u8) << fm::OFFSET
+            | (tr::Variant::from(trigger) as u8) << tr::OFFSET
+            | watermark << fth::OFFSET;
+        self.bus
+            .write(ReadWriteRegisterAddress::FifoCtrlReg, fifo_ctrl_reg)
+            .await?;
+        Ok(())
+    }
+
+    /// Sets the FIFO watermark by read-modify-writing only the `FTH` field of `FIFO_CTRL_REG (0x2E)`, keeping the FIFO mode and trigger, then reads the field back.
+    ///
+    /// `level` must be in `0..=31`; anything larger returns [`Error::ThresholdOutOfRange`] without bus traffic. Returns [`Error::VerificationFailed`] if the read-back level doesn't match `level`.
+    /// The level isn't part of `Config`, so [`Lis3dh::reset_fifo`] and [`Lis3dh::reconfigure`] restore the configured watermark and [`Lis3dh::verify_config`] reports a mismatch while a different one is set.
+    pub async fn set_fifo_watermark(&mut self, level: u8) -> Result<(), Error<Bus::BusError>> {
+        use fifo_ctrl_reg::fth;
+
+        if level >= 1 << fth::WIDTH {
+            return Err(Error::ThresholdOutOfRange);
+        }
+        self.modify_field(
+            ReadWriteRegisterAddress::FifoCtrlReg,
+            fth::OFFSET,
+            fth::WIDTH,
+            level,
+        )
+        .await?;
+        let found = self.read_field::<fth::Field>().await?;
+        if found != level {
+            return Err(Error::VerificationFailed {
+                expected: level,
+                found,
+            });
+        }
+        Ok(())
+    }
+}
+
+// Temperature sensor commands.
+
+impl<Bus, Config> Lis3dh<Bus, Config>
+where
+    Bus: Lis3dhBus,
+    Config: ValidLis3dhConfig<
+        AdcEn = temp_cfg_reg::adc_en::AdcEnabled,
+        TempEn = temp_cfg_reg::temp_en::TempEnabled,
+        Bdu = ctrl_reg4::bdu::BlockDataUpdate,
+    >,
+{
+    /// Reads the temperature sensor from `OUT_ADC3_L (0x0C)` and `OUT_ADC3_H (0x0D)` and returns it in °C.
+    ///
+    /// The temperature sensor requires the ADC, the sensor itself and block data update to be enabled, so this is only available for such configs.
+    /// The output is 8-bit in low power mode (1 digit/°C) and 10-bit otherwise (4 digits/°C). The sensor only measures temperature change, so the absolute value assumes the output is 0 at the nominal 25°C; expect an offset of a few degrees between parts.
+    pub async fn read_temperature(&mut self) -> Result<f32, Error<Bus::BusError>> {
+        const TEMPERATURE_REFERENCE_C: f32 = 25.0;
+
+        let mut result = [0; 2];
+        self.bus
+            .read_multiple(ReadOnlyRegisterAddress::OutAdc3L, &mut result)
+            .await?;
+        let temperature_counts = adc_raw_into_i16::<Config>(result) as f32;
+
+        let temperature_delta = match <Config::Resolution as resolution::Property>::VARIANT {
+            resolution::Variant::R8Bit => temperature_counts,
+            resolution::Variant::R10Bit | resolution::Variant::R12Bit => temperature_counts / 4.0,
+        };
+        Ok(TEMPERATURE_REFERENCE_C + temperature_delta)
+    }
+}
+
+// ADC commands.
+
+impl<Bus, Config> Lis3dh<Bus, Config>
+where
+    Bus: Lis3dhBus,
+    Config: ValidLis3dhConfig<AdcEn = temp_cfg_reg::adc_en::AdcEnabled>,
+{
+    /// Reads an auxiliary ADC channel and returns its right-justified count: 8-bit in low power mode and 10-bit otherwise.
+    ///
+    /// The ADC must be enabled, so this is only available for such configs. Use [`Lis3dh::adc_counts_to_millivolts`] to convert the count to a voltage.
+    pub async fn read_adc(&mut self, channel: AdcChannel) -> Result<i16, Error<Bus::BusError>> {
+        let mut result = [0; 2];
+        self.bus
+            .read_multiple(channel.low_address(), &mut result)
+            .await?;
+        Ok(adc_raw_into_i16::<Config>(result))
+    }
+
+    /// Reads the acceleration vector and ADC channel 1 together, e.g. to log an external analog signal such as a strain gauge alongside the motion.
+    ///
+    /// `OUT_ADC1 (0x08)` and `OUT_X_L (0x28)` aren't contiguous, so this takes two bursts: ADC1 first, then the acceleration. Both are sampled at the configured output data rate, so with block data update off a conversion may land between the bursts. The ADC count is right-justified like [`Lis3dh::read_adc`] and covers the nominal 800 mV to 1600 mV input range. Only available with the ADC enabled and an active output data rate.
+    pub async fn read_accel_and_adc1(
+        &mut self,
+    ) -> Result<(AccelerationVector, i16), Error<Bus::BusError>>
+    where
+        Config::Odr: ctrl_reg1::odr::Active,
+    {
+        let adc1 = self.read_adc(AdcChannel::One).await?;
+        let accel = self.get_accel_vector().await?;
+        Ok((accel, adc1))
+    }
+
+    /// Converts a count returned by [`Lis3dh::read_adc`] to millivolts at the configured resolution, see [`out_adc::counts_to_millivolts`].
+    pub fn adc_counts_to_millivolts(&self, counts: i16) -> i32 {
+        out_adc::counts_to_millivolts::<Config::Resolution>(counts)
+    }
 }
 
 // Register read/write commands.
@@ -124,6 +2091,37 @@ where
         Ok(self.bus.read(register_address).await?)
     }
 
+    /// Reads a single field back from its register and decodes it into the field module's `Variant`, e.g. `read_field::<ctrl_reg1::odr::Field>()`.
+    ///
+    /// Returns [`Error::InvalidFieldValue`] if the field holds a raw value with no variant.
+    pub async fn read_field<F: Field>(&mut self) -> Result<F::Variant, Error<Bus::BusError>> {
+        let register = self.bus.read(F::REGISTER).await?;
+        let raw = (register >> F::OFFSET) & ((1 << F::WIDTH) - 1);
+        F::Variant::from_raw(raw).ok_or(Error::InvalidFieldValue { raw })
+    }
+
+    /// Safe counterpart of [`Lis3dh::read_multiple_registers`]: reads consecutive registers starting at `start_address` into `result`, after checking the whole range lies within a block of readable registers.
+    ///
+    /// Returns [`Error::InvalidRange`] without bus access if `result` is empty or the range would cross a reserved address (`0x0E` or `0x10` to `0x1D`) or run past `ACT_DUR (0x3F)`.
+    pub async fn read_register_range(
+        &mut self,
+        start_address: impl Into<RegisterAddress>,
+        result: &mut [u8],
+    ) -> Result<(), Error<Bus::BusError>> {
+        let start_address = start_address.into();
+        let first = start_address.byte_address() as usize;
+        let in_bounds = READABLE_REGISTER_BLOCKS
+            .iter()
+            .any(|&(block_first, block_last)| {
+                (block_first as usize..=block_last as usize).contains(&first)
+                    && first + result.len() <= block_last as usize + 1
+            });
+        if result.is_empty() || !in_bounds {
+            return Err(Error::InvalidRange);
+        }
+        Ok(self.bus.read_multiple(start_address, result).await?)
+    }
+
     /// Read multiple consecutive register values from the lis3dh. The address is incremented by 1 then read for every byte in the read buffer passed.
     /// # Safety
     /// This function does not check if all registers addresses being read are valid. Attempting to read from invalid addresses may lead to undefined behaviour.
@@ -146,6 +2144,18 @@ where
         Ok(self.bus.write(register_address, value).await?)
     }
 
+    /// Read-modify-write a single register of the lis3dh: reads the current value, applies `f` and writes the result back.
+    /// # Safety
+    /// There is no check for the validity of the byte returned by `f`. Invalid register configurations may lead to undefined behaviour.
+    pub async unsafe fn modify_register(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        f: impl FnOnce(u8) -> u8,
+    ) -> Result<(), Error<Bus::BusError>> {
+        let value = self.bus.read(register_address).await?;
+        Ok(self.bus.write(register_address, f(value)).await?)
+    }
+
     /// Write multiple consecutive register values to the lis3dh. The address and `values` index is incremented by 1 then written for every byte in the write buffer passed.
     /// # Safety
     /// This function does not check if all registers being broadcast to are writable so you **must** guarantee registers in the broadcast are safe to write to.
@@ -153,7 +2163,7 @@ where
     pub async unsafe fn write_multiple_registers(
         &mut self,
         start_address: ReadWriteRegisterAddress,
-        values: &mut [u8],
+        values: &[u8],
     ) -> Result<(), Error<Bus::BusError>> {
         Ok(self.bus.write_multiple(start_address, values).await?)
     }
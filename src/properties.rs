@@ -70,6 +70,8 @@ pub mod gravity_coefficient {
 
     pub trait Property {
         const GRAVITY_COEFFICIENT: f32;
+        /// [`Property::GRAVITY_COEFFICIENT`] in milli-g/digit. Every coefficient is an exact multiple of 1 mg, which allows integer-only conversions.
+        const MILLI_G_PER_DIGIT: u16;
     }
 
     pub struct GravityCoefficient<Fs, Res>
@@ -85,25 +87,361 @@ pub mod gravity_coefficient {
         Fs: crate::registers::ctrl_reg4::fs::State,
         Resolution: super::resolution::Property,
     {
-        const GRAVITY_COEFFICIENT: f32 = {
+        const GRAVITY_COEFFICIENT: f32 = Self::MILLI_G_PER_DIGIT as f32 / 1000.0;
+
+        const MILLI_G_PER_DIGIT: u16 = {
             use crate::registers::ctrl_reg4::fs;
             match (Fs::VARIANT, Resolution::VARIANT) {
-                (fs::Variant::S2G, super::resolution::Variant::R8Bit) => 0.016,
-                (fs::Variant::S2G, super::resolution::Variant::R10Bit) => 0.004,
-                (fs::Variant::S2G, super::resolution::Variant::R12Bit) => 0.001,
+                (fs::Variant::S2G, super::resolution::Variant::R8Bit) => 16,
+                (fs::Variant::S2G, super::resolution::Variant::R10Bit) => 4,
+                (fs::Variant::S2G, super::resolution::Variant::R12Bit) => 1,
+
+                (fs::Variant::S4G, super::resolution::Variant::R8Bit) => 32,
+                (fs::Variant::S4G, super::resolution::Variant::R10Bit) => 8,
+                (fs::Variant::S4G, super::resolution::Variant::R12Bit) => 2,
+
+                (fs::Variant::S8G, super::resolution::Variant::R8Bit) => 64,
+                (fs::Variant::S8G, super::resolution::Variant::R10Bit) => 16,
+                (fs::Variant::S8G, super::resolution::Variant::R12Bit) => 4,
+
+                (fs::Variant::S16G, super::resolution::Variant::R8Bit) => 192,
+                (fs::Variant::S16G, super::resolution::Variant::R10Bit) => 48,
+                (fs::Variant::S16G, super::resolution::Variant::R12Bit) => 12,
+            }
+        };
+    }
+}
+
+/// # Threshold Coefficient
+/// The acceleration represented by 1 LSb of the interrupt and activity threshold registers (`INT1_THS`, `INT2_THS`, `CLICK_THS` and `ACT_THS`) is a result of the configuration of bit-field [`crate::registers::ctrl_reg4::fs`].
+///
+/// | Full Scale ([`crate::registers::ctrl_reg4::fs`]) | [`threshold_coefficient`] (mg/LSb) |
+/// |:----------------:|:-----:|
+/// | `S2G`            | 16    |
+/// | `S4G`            | 32    |
+/// | `S8G`            | 62    |
+/// | `S16G`           | 186   |
+pub mod threshold_coefficient {
+    pub trait Property {
+        const MILLI_G_PER_LSB: u16;
+    }
+
+    pub struct ThresholdCoefficient<Fs>
+    where
+        Fs: crate::registers::ctrl_reg4::fs::State,
+    {
+        _p: core::marker::PhantomData<Fs>,
+    }
+
+    impl<Fs> Property for ThresholdCoefficient<Fs>
+    where
+        Fs: crate::registers::ctrl_reg4::fs::State,
+    {
+        const MILLI_G_PER_LSB: u16 = {
+            use crate::registers::ctrl_reg4::fs;
+            match Fs::VARIANT {
+                fs::Variant::S2G => 16,
+                fs::Variant::S4G => 32,
+                fs::Variant::S8G => 62,
+                fs::Variant::S16G => 186,
+            }
+        };
+    }
+
+    /// Converts a threshold in milli-g to the nearest count of a 7-bit threshold register, saturating at 127.
+    pub(crate) fn milli_g_to_counts<T: Property>(milli_g: u16) -> u8 {
+        let lsb = T::MILLI_G_PER_LSB as u32;
+        let counts = (milli_g as u32 + lsb / 2) / lsb;
+        counts.min(0x7F) as u8
+    }
+
+    /// Returns the mg/LSb of the threshold registers (`INT1_THS`, `CLICK_THS`, `ACT_THS`) at the full scale `Fs`.
+    pub fn mg_per_count<Fs: crate::registers::ctrl_reg4::fs::State>() -> u16 {
+        ThresholdCoefficient::<Fs>::MILLI_G_PER_LSB
+    }
+
+    /// Converts a threshold register count at the full scale `Fs` to milli-g.
+    pub fn count_to_mg<Fs: crate::registers::ctrl_reg4::fs::State>(count: u8) -> u16 {
+        count as u16 * mg_per_count::<Fs>()
+    }
+
+    /// Converts milli-g to the nearest threshold register count at the full scale `Fs`, saturating at the 7-bit maximum of 127.
+    pub fn mg_to_count<Fs: crate::registers::ctrl_reg4::fs::State>(mg: u16) -> u8 {
+        milli_g_to_counts::<ThresholdCoefficient<Fs>>(mg)
+    }
+
+    /// A threshold register count at the full scale `Fs`, e.g. for [`crate::Lis3dh::set_int1_threshold`].
+    ///
+    /// The full scale is part of the type, so a threshold set at ±2 g can't be reused verbatim after switching to ±16 g, where the same count means a different acceleration.
+    pub struct Threshold<Fs>
+    where
+        Fs: crate::registers::ctrl_reg4::fs::State,
+    {
+        raw: u8,
+        _p: core::marker::PhantomData<Fs>,
+    }
+
+    impl<Fs> Threshold<Fs>
+    where
+        Fs: crate::registers::ctrl_reg4::fs::State,
+    {
+        /// Converts milli-g to the nearest count at the full scale `Fs`, saturating at 127 counts.
+        pub fn from_mg(mg: u16) -> Self {
+            Self::from_raw(mg_to_count::<Fs>(mg))
+        }
+
+        /// Wraps a raw register count, saturating at 127.
+        pub fn from_raw(raw: u8) -> Self {
+            Threshold {
+                raw: raw.min(0x7F),
+                _p: core::marker::PhantomData,
+            }
+        }
+
+        /// Returns the raw register count.
+        pub fn raw(&self) -> u8 {
+            self.raw
+        }
+
+        /// Returns the threshold in milli-g at the full scale `Fs`.
+        pub fn mg(&self) -> u16 {
+            count_to_mg::<Fs>(self.raw)
+        }
+    }
+
+    // Written by hand rather than derived, as a derive would also require the full scale type-state to be `Clone` and `Copy`.
+    impl<Fs: crate::registers::ctrl_reg4::fs::State> Clone for Threshold<Fs> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<Fs: crate::registers::ctrl_reg4::fs::State> Copy for Threshold<Fs> {}
+}
+
+/// # Output Data Rate Frequency
+/// The output data rate in Hz is a result of the configuration of the [`crate::registers::ctrl_reg1::odr`] and [`crate::registers::ctrl_reg1::lp_en`] bit-fields, as the raw value `0b1001` selects a different rate depending on the power mode.
+///
+/// | [`crate::registers::ctrl_reg1::odr`] | [`crate::registers::ctrl_reg1::lp_en`] | [`odr_hz`] (Hz) |
+/// |:------------:|:---------------:|:-------:|
+/// | `PowerDown`  | Any             | 0.0     |
+/// | `F1Hz`       | Any             | 1.0     |
+/// | `F10Hz`      | Any             | 10.0    |
+/// | `F25Hz`      | Any             | 25.0    |
+/// | `F50Hz`      | Any             | 50.0    |
+/// | `F100Hz`     | Any             | 100.0   |
+/// | `F200Hz`     | Any             | 200.0   |
+/// | `F400Hz`     | Any             | 400.0   |
+/// | `F1600Hz`    | Low-power       | 1600.0  |
+/// | `F1344Hz`    | Normal          | 1344.0  |
+/// | `F5376Hz`    | Low-power       | 5376.0  |
+///
+/// The usable (Nyquist) bandwidth of the output is half the output data rate.
+pub mod odr_hz {
+    pub trait Property {
+        const FREQUENCY_HZ: f32;
+        /// Nyquist bandwidth of the output in Hz, `FREQUENCY_HZ / 2`.
+        const BANDWIDTH_HZ: f32 = Self::FREQUENCY_HZ / 2.0;
+    }
+
+    pub struct OdrHz<Odr, LpEn>
+    where
+        Odr: crate::registers::ctrl_reg1::odr::State,
+        LpEn: crate::registers::ctrl_reg1::lp_en::State,
+    {
+        _p: core::marker::PhantomData<(Odr, LpEn)>,
+    }
 
-                (fs::Variant::S4G, super::resolution::Variant::R8Bit) => 0.032,
-                (fs::Variant::S4G, super::resolution::Variant::R10Bit) => 0.008,
-                (fs::Variant::S4G, super::resolution::Variant::R12Bit) => 0.002,
+    impl<Odr, LpEn> Property for OdrHz<Odr, LpEn>
+    where
+        Odr: crate::registers::ctrl_reg1::odr::State,
+        LpEn: crate::registers::ctrl_reg1::lp_en::State,
+    {
+        const FREQUENCY_HZ: f32 = {
+            use crate::registers::ctrl_reg1::{lp_en, odr};
+            match (Odr::VARIANT, LpEn::VARIANT) {
+                (odr::Variant::PowerDown, _) => 0.0,
+                (odr::Variant::F1Hz, _) => 1.0,
+                (odr::Variant::F10Hz, _) => 10.0,
+                (odr::Variant::F25Hz, _) => 25.0,
+                (odr::Variant::F50Hz, _) => 50.0,
+                (odr::Variant::F100Hz, _) => 100.0,
+                (odr::Variant::F200Hz, _) => 200.0,
+                (odr::Variant::F400Hz, _) => 400.0,
+                (odr::Variant::F1600Hz, lp_en::Variant::LowPowerMode) => 1600.0,
+                (odr::Variant::F1344Hz, lp_en::Variant::NormalPowerMode) => 1344.0,
+                (odr::Variant::F1344Hz, lp_en::Variant::LowPowerMode) => 5376.0, // odr::Variant::F5376HZ
+                (odr::Variant::F1600Hz, lp_en::Variant::NormalPowerMode) => unreachable!(),
+            }
+        };
+    }
 
-                (fs::Variant::S8G, super::resolution::Variant::R8Bit) => 0.064,
-                (fs::Variant::S8G, super::resolution::Variant::R10Bit) => 0.016,
-                (fs::Variant::S8G, super::resolution::Variant::R12Bit) => 0.004,
+    /// An interrupt duration register count at the output data rate `Odr`, where 1 LSb = 1/ODR, e.g. for [`crate::Lis3dh::set_int1_duration`].
+    ///
+    /// The output data rate is part of the type, so a duration set at 100 Hz can't be reused verbatim after switching to 400 Hz, where the same count lasts a quarter as long.
+    pub struct Duration<Odr>
+    where
+        Odr: Property,
+    {
+        raw: u8,
+        _p: core::marker::PhantomData<Odr>,
+    }
+
+    impl<Odr> Duration<Odr>
+    where
+        Odr: Property,
+    {
+        /// Converts milliseconds to the nearest count at the output data rate `Odr`, saturating at 127 counts.
+        pub fn from_ms(ms: u32) -> Self {
+            Self::from_raw(crate::registers::int1_duration::counts_from_ms::<Odr>(ms))
+        }
 
-                (fs::Variant::S16G, super::resolution::Variant::R8Bit) => 0.192,
-                (fs::Variant::S16G, super::resolution::Variant::R10Bit) => 0.048,
-                (fs::Variant::S16G, super::resolution::Variant::R12Bit) => 0.012,
+        /// Wraps a raw register count, saturating at 127.
+        pub fn from_raw(raw: u8) -> Self {
+            Duration {
+                raw: raw.min(0x7F),
+                _p: core::marker::PhantomData,
             }
+        }
+
+        /// Returns the raw register count.
+        pub fn raw(&self) -> u8 {
+            self.raw
+        }
+
+        /// Returns the duration in milliseconds at the output data rate `Odr`, or 0 at power-down.
+        pub fn ms(&self) -> f32 {
+            if Odr::FREQUENCY_HZ == 0.0 {
+                return 0.0;
+            }
+            self.raw as f32 * 1000.0 / Odr::FREQUENCY_HZ
+        }
+    }
+
+    // Written by hand rather than derived, as a derive would also require the output data rate property to be `Clone` and `Copy`.
+    impl<Odr: Property> Clone for Duration<Odr> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<Odr: Property> Copy for Duration<Odr> {}
+}
+
+/// # High-pass Filter Cutoff Frequency
+/// The approximate -3 dB cutoff frequency of the high-pass filter is a result of the configuration of bit-field [`crate::registers::ctrl_reg2::hpcf`] and property [`odr_hz`], as the output data rate divided by a coefficient `c`.
+///
+/// | [`crate::registers::ctrl_reg2::hpcf`] | `c` | At 100 Hz (Hz) | At 400 Hz (Hz) |
+/// |:----------------------------------:|:---:|:--------------:|:--------------:|
+/// | `Hpcf0`                            | 50  | 2.0            | 8.0            |
+/// | `Hpcf1`                            | 100 | 1.0            | 4.0            |
+/// | `Hpcf2`                            | 200 | 0.5            | 2.0            |
+/// | `Hpcf3`                            | 400 | 0.25           | 1.0            |
+///
+/// The datasheet's table rounds these values per output data rate, so treat them as design estimates rather than exact corners. With an output data rate of power-down the cutoff is 0.
+pub mod hp_cutoff_hz {
+    use crate::registers::ctrl_reg2::hpcf;
+
+    pub trait Property {
+        const FREQUENCY_HZ: f32;
+    }
+
+    pub struct HpCutoffHz<OdrHz, Hpcf>
+    where
+        OdrHz: super::odr_hz::Property,
+        Hpcf: crate::registers::ctrl_reg2::hpcf::State,
+    {
+        _p: core::marker::PhantomData<(OdrHz, Hpcf)>,
+    }
+
+    impl<OdrHz, Hpcf> Property for HpCutoffHz<OdrHz, Hpcf>
+    where
+        OdrHz: super::odr_hz::Property,
+        Hpcf: crate::registers::ctrl_reg2::hpcf::State,
+    {
+        const FREQUENCY_HZ: f32 = hpcf_cutoff_hz::<OdrHz>(&Hpcf::VARIANT);
+    }
+
+    /// Returns the approximate cutoff frequency of the `hpcf` setting at the output data rate `OdrHz`, per the table above.
+    pub const fn hpcf_cutoff_hz<OdrHz: super::odr_hz::Property>(hpcf: &hpcf::Variant) -> f32 {
+        let c = match hpcf {
+            hpcf::Variant::Hpcf0 => 50.0,
+            hpcf::Variant::Hpcf1 => 100.0,
+            hpcf::Variant::Hpcf2 => 200.0,
+            hpcf::Variant::Hpcf3 => 400.0,
         };
+        OdrHz::FREQUENCY_HZ / c
+    }
+
+    /// Returns the `hpcf` setting whose cutoff frequency at the output data rate `OdrHz` is nearest to `target_hz`, e.g. to pick the [`crate::registers::ctrl_reg2::hpcf`] type-state for a filter design.
+    pub fn hpcf_for_cutoff_hz<OdrHz: super::odr_hz::Property>(target_hz: f32) -> hpcf::Variant {
+        let distance = |hpcf: &hpcf::Variant| (hpcf_cutoff_hz::<OdrHz>(hpcf) - target_hz).abs();
+        let settings = [
+            hpcf::Variant::Hpcf0,
+            hpcf::Variant::Hpcf1,
+            hpcf::Variant::Hpcf2,
+            hpcf::Variant::Hpcf3,
+        ];
+        let nearest = settings
+            .into_iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)));
+        // The array isn't empty, so there always is a nearest setting.
+        nearest.unwrap_or(hpcf::Variant::Hpcf0)
+    }
+}
+
+/// # Operating Mode
+/// The operating mode combines the entangled bit-fields [`crate::registers::ctrl_reg1::odr`], [`crate::registers::ctrl_reg1::lp_en`] and [`crate::registers::ctrl_reg4::hr`], which together resolve the properties [`odr_hz`] and [`resolution`].
+///
+/// Bundling the three fields resolves the ambiguity of the raw ODR value `0b1001` (1344 Hz in normal power mode, 5376 Hz in low power mode) and the coupling of the resolution to the power mode in one place. An [`operating_mode::OperatingMode`] can only be named with entitled combinations, e.g. high resolution in low power mode fails to compile. Pass one to [`crate::config::Config::from_operating_mode`] or [`crate::Lis3dh::set_operating_mode`].
+pub mod operating_mode {
+    use crate::registers::{ctrl_reg1, ctrl_reg4, Entitled};
+
+    pub trait Property {
+        /// Output data rate type-state.
+        type Odr: ctrl_reg1::odr::State + Entitled<Self::LpEn>;
+        /// Power mode type-state.
+        type LpEn: ctrl_reg1::lp_en::State;
+        /// Resolution mode type-state.
+        type Hr: ctrl_reg4::hr::State + Entitled<Self::LpEn>;
+
+        /// Output data rate in Hz, see [`super::odr_hz`].
+        const FREQUENCY_HZ: f32;
+        /// Acceleration resolution, see [`super::resolution`].
+        const RESOLUTION: super::resolution::Variant;
+
+        /// Splits the operating mode into its type-states.
+        fn into_states(self) -> (Self::Odr, Self::LpEn, Self::Hr);
+    }
+
+    pub struct OperatingMode<Odr, LpEn, Hr>
+    where
+        Odr: ctrl_reg1::odr::State + Entitled<LpEn>,
+        LpEn: ctrl_reg1::lp_en::State,
+        Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+    {
+        pub data_rate: Odr,
+        pub power_mode: LpEn,
+        pub resolution_mode: Hr,
+    }
+
+    impl<Odr, LpEn, Hr> Property for OperatingMode<Odr, LpEn, Hr>
+    where
+        Odr: ctrl_reg1::odr::State + Entitled<LpEn>,
+        LpEn: ctrl_reg1::lp_en::State,
+        Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+    {
+        type Odr = Odr;
+        type LpEn = LpEn;
+        type Hr = Hr;
+
+        const FREQUENCY_HZ: f32 =
+            <super::odr_hz::OdrHz<Odr, LpEn> as super::odr_hz::Property>::FREQUENCY_HZ;
+        const RESOLUTION: super::resolution::Variant =
+            <super::resolution::Resolution<LpEn, Hr> as super::resolution::Property>::VARIANT;
+
+        fn into_states(self) -> (Odr, LpEn, Hr) {
+            (self.data_rate, self.power_mode, self.resolution_mode)
+        }
     }
 }
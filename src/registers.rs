@@ -73,7 +73,7 @@
 //! The mandatory hardware state of the sensor range bit-field when the sensor is disabled can be enforced by the compiler using Entitlements.
 //!
 //! In code this would look like:
-//! ```
+//! ```ignore
 //!  // The compiler enforces that sensor 1 can only be disabled if the sensor range is set to disabled.
 //!
 //!  impl Entitled<sensor_1_range::RangeDisabled> for sensor_1_enable::SensorDisabled {}
@@ -138,12 +138,39 @@
 // ACT_THS                    rw     3E              011 1110           00000000     Activity interrupt threshold register.
 // ACT_DUR                    rw     3F              011 1111           00000000
 
+pub mod act_dur;
+pub mod act_ths;
+pub mod click_cfg;
+pub mod click_src;
+pub mod click_ths;
 pub mod ctrl_reg0;
 pub mod ctrl_reg1;
+pub mod ctrl_reg2;
+pub mod ctrl_reg3;
 pub mod ctrl_reg4;
+pub mod ctrl_reg5;
+pub mod ctrl_reg6;
+pub mod fifo_ctrl_reg;
+pub mod fifo_src_reg;
+pub mod int1_cfg;
+pub mod int1_duration;
+pub mod int1_src;
+pub mod int1_ths;
+pub mod int2_cfg;
+pub mod int2_duration;
+pub mod int2_src;
+pub mod int2_ths;
+pub mod out_adc;
+pub mod status_reg;
+pub mod status_reg_aux;
 pub mod temp_cfg_reg;
+pub mod time_latency;
+pub mod time_limit;
+pub mod time_window;
+pub mod who_am_i;
 
 // Register Addresses
+#[derive(Clone, Copy)]
 pub enum ReadWriteRegisterAddress {
     /// CTRL_REG0
     CtrlReg0 = 0x1E,
@@ -161,6 +188,8 @@ pub enum ReadWriteRegisterAddress {
     CtrlReg5 = 0x24,
     /// CTRL_REG6
     CtrlReg6 = 0x25,
+    /// REFERENCE
+    Reference = 0x26,
     /// FIFO_CTRL_REG
     FifoCtrlReg = 0x2E,
     /// INT1_CFG
@@ -191,6 +220,7 @@ pub enum ReadWriteRegisterAddress {
     ActDur = 0x3F,
 }
 
+#[derive(Clone, Copy)]
 pub enum ReadOnlyRegisterAddress {
     /// STATUS_REG_AUX
     StatusRegAux = 0x07,
@@ -208,8 +238,6 @@ pub enum ReadOnlyRegisterAddress {
     OutAdc3H = 0x0D,
     /// WHO_AM_I
     WhoAmI = 0x0F,
-    /// REFERENCE
-    Reference = 0x26,
     /// STATUS_REG
     StatusReg = 0x27,
     /// OUT_X_L
@@ -234,6 +262,7 @@ pub enum ReadOnlyRegisterAddress {
     ClickSrc = 0x39,
 }
 
+#[derive(Clone, Copy)]
 pub enum RegisterAddress {
     ReadOnly(ReadOnlyRegisterAddress),
     ReadWrite(ReadWriteRegisterAddress),
@@ -260,6 +289,27 @@ impl RegisterAddress {
     }
 }
 
+/// Decodes the raw bits of a field, shifted down to bit 0, into the field's `Variant`.
+pub trait FromRaw: Sized {
+    /// Returns `None` if no variant has the raw value, e.g. a reserved output data rate.
+    fn from_raw(raw: u8) -> Option<Self>;
+}
+
+// Fields whose every raw value is valid, like `fifo_ctrl_reg::fth`, decode to the raw value itself.
+impl FromRaw for u8 {
+    fn from_raw(raw: u8) -> Option<Self> {
+        Some(raw)
+    }
+}
+
+/// Location of a field within a writable register, implemented by the `Field` marker in each field module so the field can be read back with [`crate::Lis3dh::read_field`].
+pub trait Field {
+    const REGISTER: ReadWriteRegisterAddress;
+    const WIDTH: u8;
+    const OFFSET: u8;
+    type Variant: FromRaw;
+}
+
 // The Entitled trait is used to express inter-bit-field relationships to the compiler.
 pub trait Entitled<T> {}
 
@@ -270,7 +320,7 @@ macro_rules! define_state_renderer {
     ) => {
         paste::paste!{
             #[doc = "Render `" $($module) "`, `" + "` fields from type-states to single byte (hardware-state) to be written to register."]
-            pub(crate) fn render_hardware_state < $( [<$module:camel>] ),+ >() -> u8
+            pub(crate) const fn render_hardware_state < $( [<$module:camel>] ),+ >() -> u8
             where
                 // Create "where" bound for each Type-State.
                 $( [<$module:camel>] : $module::State ),+
@@ -285,3 +335,35 @@ macro_rules! define_state_renderer {
 }
 
 pub(crate) use define_state_renderer;
+
+/// Macro that takes the field module's register and the variants of its `Variant` enum, and creates the `Field` marker and the [`FromRaw`] implementation used to read the field back.
+macro_rules! define_field_reader {
+    (
+        $register:ident, $( $variant:ident ),+
+    ) => {
+        /// Marker for reading this field back, see [`crate::Lis3dh::read_field`].
+        pub struct Field;
+
+        impl $crate::registers::Field for Field {
+            const REGISTER: $crate::registers::ReadWriteRegisterAddress =
+                $crate::registers::ReadWriteRegisterAddress::$register;
+            const WIDTH: u8 = WIDTH;
+            const OFFSET: u8 = OFFSET;
+            type Variant = Variant;
+        }
+
+        impl $crate::registers::FromRaw for Variant {
+            fn from_raw(raw: u8) -> Option<Self> {
+                // Create a check for each variant's raw value.
+                $(
+                    if raw == Variant::$variant as u8 {
+                        return Some(Variant::$variant);
+                    }
+                )+
+                None
+            }
+        }
+    };
+}
+
+pub(crate) use define_field_reader;
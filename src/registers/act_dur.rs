@@ -0,0 +1,22 @@
+//! # ACT_DUR (3Fh)
+//! ## Fields:
+//! - `actd`: Sleep-to-wake, return-to-sleep duration.
+//!
+//! The duration in seconds is `(8 * ACTD + 1) / ODR`.
+
+use crate::properties::odr_hz;
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::ActDur as u8;
+
+pub const ACTD_OFFSET: u8 = 0;
+pub const ACTD_WIDTH: u8 = 8;
+
+/// Converts a duration in milliseconds to the nearest `ACTD` count at the output data rate `Hz`, inverting `(8 * ACTD + 1) / ODR`.
+///
+/// Durations shorter than a single sample give 0 and durations longer than the register can express saturate at 255.
+pub fn counts_from_ms<Hz: odr_hz::Property>(duration_ms: u32) -> u8 {
+    let samples = duration_ms as f32 * Hz::FREQUENCY_HZ / 1000.0;
+    // Float to int `as` casts saturate, which clamps negative counts to 0 and large counts to 255.
+    ((samples - 1.0) / 8.0 + 0.5) as u8
+}
@@ -0,0 +1,13 @@
+//! # ACT_THS (3Eh)
+//! ## Fields:
+//! - `acth`: Sleep-to-wake, return-to-sleep activation threshold in low-power mode, see [`crate::properties::threshold_coefficient`] for the value of 1 LSb.
+//!
+//! Bit 7 is reserved and must be 0.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::ActThs as u8;
+
+pub const ACTH_OFFSET: u8 = 0;
+pub const ACTH_WIDTH: u8 = 7;
+pub const ACTH_MAX: u8 = (1 << ACTH_WIDTH) - 1;
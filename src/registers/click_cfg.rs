@@ -0,0 +1,106 @@
+//! # CLICK_CFG (38h)
+//! ## Fields:
+//! - `xs`: Enable interrupt single click on X-axis.
+//! - `xd`: Enable interrupt double click on X-axis.
+//! - `ys`: Enable interrupt single click on Y-axis.
+//! - `yd`: Enable interrupt double click on Y-axis.
+//! - `zs`: Enable interrupt single click on Z-axis.
+//! - `zd`: Enable interrupt double click on Z-axis.
+
+use crate::properties::odr_hz;
+use crate::registers::{click_ths, time_limit, ReadWriteRegisterAddress};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::ClickCfg as u8;
+
+pub const XS_OFFSET: u8 = 0;
+pub const XD_OFFSET: u8 = 1;
+pub const YS_OFFSET: u8 = 2;
+pub const YD_OFFSET: u8 = 3;
+pub const ZS_OFFSET: u8 = 4;
+pub const ZD_OFFSET: u8 = 5;
+
+/// Axes on which a click is detected.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ClickAxes {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+/// Click (tap) detection configuration, written to `CLICK_CFG (0x38)`, `CLICK_THS (0x3A)`, `TIME_LIMIT (0x3B)`, `TIME_LATENCY (0x3C)` and `TIME_WINDOW (0x3D)` by [`crate::Lis3dh::configure_click`].
+///
+/// Thresholds are in digits of the full scale (1 LSb = full scale / 128) and times are in periods of the output data rate.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ClickConfig {
+    /// Axes that raise the interrupt on a single click.
+    pub single: ClickAxes,
+    /// Axes that raise the interrupt on a double click.
+    pub double: ClickAxes,
+    /// Acceleration that must be exceeded to detect a click, 0 to 127. Larger values saturate at 127.
+    pub threshold: u8,
+    /// Keep the interrupt latched until `CLICK_SRC` is read.
+    pub latch_interrupt: bool,
+    /// Maximum time the acceleration can stay above the threshold for it to count as a click, 0 to 127. Larger values saturate at 127.
+    pub time_limit: u8,
+    /// Time after the first click during which clicks are ignored when detecting a double click.
+    pub time_latency: u8,
+    /// Time after the latency in which the second click of a double click must start.
+    pub time_window: u8,
+}
+
+impl ClickConfig {
+    /// Renders the `CLICK_CFG` register byte.
+    pub(crate) fn click_cfg_byte(&self) -> u8 {
+        let ClickConfig { single, double, .. } = self;
+        (single.x as u8) << XS_OFFSET
+            | (double.x as u8) << XD_OFFSET
+            | (single.y as u8) << YS_OFFSET
+            | (double.y as u8) << YD_OFFSET
+            | (single.z as u8) << ZS_OFFSET
+            | (double.z as u8) << ZD_OFFSET
+    }
+
+    /// Renders the `CLICK_THS` to `TIME_WINDOW` register bytes.
+    pub(crate) fn timing_bytes(&self) -> [u8; 4] {
+        [
+            (self.latch_interrupt as u8) << click_ths::LIR_CLICK_OFFSET
+                | self.threshold.min(click_ths::THS_MAX) << click_ths::THS_OFFSET,
+            self.time_limit.min(time_limit::TLI_MAX) << time_limit::TLI_OFFSET,
+            self.time_latency,
+            self.time_window,
+        ]
+    }
+}
+
+/// Double click (tap) timing in milliseconds, converted to periods of the output data rate by [`crate::Lis3dh::configure_double_tap`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DoubleTapTiming {
+    /// Maximum time the acceleration can stay above the threshold for it to count as a tap, see [`ClickConfig::time_limit`].
+    pub time_limit_ms: u16,
+    /// Time after the first tap during which taps are ignored, see [`ClickConfig::time_latency`].
+    pub time_latency_ms: u16,
+    /// Time after the latency in which the second tap must start, see [`ClickConfig::time_window`].
+    pub time_window_ms: u16,
+}
+
+impl DoubleTapTiming {
+    /// Converts the timing to the nearest `TIME_LIMIT`, `TIME_LATENCY` and `TIME_WINDOW` counts at the output data rate `Hz`.
+    ///
+    /// Returns `None` if a non-zero time is shorter than half an output data rate period, so it would round to 0, or longer than its register can express.
+    pub(crate) fn counts<Hz: odr_hz::Property>(&self) -> Option<[u8; 3]> {
+        let counts = |ms: u16, max: u8| {
+            let samples = ms as f32 * Hz::FREQUENCY_HZ / 1000.0;
+            // Float to int `as` casts saturate, so huge counts still compare greater than `max`.
+            let count = (samples + 0.5) as u32;
+            (count <= max as u32 && (count > 0 || ms == 0)).then_some(count as u8)
+        };
+        Some([
+            counts(self.time_limit_ms, time_limit::TLI_MAX)?,
+            counts(self.time_latency_ms, u8::MAX)?,
+            counts(self.time_window_ms, u8::MAX)?,
+        ])
+    }
+}
@@ -0,0 +1,58 @@
+//! # CLICK_SRC (39h)
+//! ## Fields:
+//! - `ia`: An interrupt is active.
+//! - `dclick`: Double click detected.
+//! - `sclick`: Single click detected.
+//! - `sign`: Click sign, 0 positive and 1 negative.
+//! - `z`: Click detected on the Z-axis.
+//! - `y`: Click detected on the Y-axis.
+//! - `x`: Click detected on the X-axis.
+
+use crate::acceleration_data_structs::Axis;
+use crate::registers::ReadOnlyRegisterAddress;
+
+pub const ADDR: u8 = ReadOnlyRegisterAddress::ClickSrc as u8;
+
+pub const IA_OFFSET: u8 = 6;
+pub const DCLICK_OFFSET: u8 = 5;
+pub const SCLICK_OFFSET: u8 = 4;
+pub const SIGN_OFFSET: u8 = 3;
+pub const Z_OFFSET: u8 = 2;
+pub const Y_OFFSET: u8 = 1;
+pub const X_OFFSET: u8 = 0;
+
+/// Decoded `CLICK_SRC`.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ClickSource {
+    /// `SCLICK`: a single click was detected.
+    pub single: bool,
+    /// `DCLICK`: a double click was detected.
+    pub double: bool,
+    /// `Sign`: the click was in the negative direction of `axis`.
+    pub sign: bool,
+    /// Axis the click was detected on, or `None` if no click was detected. If several axes are flagged the first of X, Y, Z is reported.
+    pub axis: Option<Axis>,
+}
+
+impl From<u8> for ClickSource {
+    fn from(value: u8) -> Self {
+        let is_set = |offset: u8| value & (1 << offset) != 0;
+        let axis = if is_set(X_OFFSET) {
+            Some(Axis::X)
+        } else if is_set(Y_OFFSET) {
+            Some(Axis::Y)
+        } else if is_set(Z_OFFSET) {
+            Some(Axis::Z)
+        } else {
+            None
+        };
+
+        ClickSource {
+            single: is_set(SCLICK_OFFSET),
+            double: is_set(DCLICK_OFFSET),
+            sign: is_set(SIGN_OFFSET),
+            axis,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! # CLICK_THS (3Ah)
+//! ## Fields:
+//! - `lir_click`: Keep the click interrupt latched until `CLICK_SRC` is read.
+//! - `ths`: Click threshold, 1 LSb = full scale / 128.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::ClickThs as u8;
+
+pub const LIR_CLICK_OFFSET: u8 = 7;
+pub const THS_OFFSET: u8 = 0;
+pub const THS_WIDTH: u8 = 7;
+pub const THS_MAX: u8 = (1 << THS_WIDTH) - 1;
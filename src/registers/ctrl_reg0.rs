@@ -30,6 +30,8 @@ pub mod sdo_pu_disc {
         SdoFloating = 0b1,
     }
 
+    crate::registers::define_field_reader!(CtrlReg0, SdoPulledUp, SdoFloating);
+
     pub struct SdoPulledUp;
     pub struct SdoFloating;
 
@@ -58,6 +60,8 @@ pub mod must_set_bits {
         MustSet = 0b0010000,
     }
 
+    crate::registers::define_field_reader!(CtrlReg0, MustSet);
+
     pub struct MustSet;
 
     impl State for MustSet {
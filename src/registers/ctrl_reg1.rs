@@ -40,6 +40,35 @@ pub mod odr {
         const VARIANT: Variant;
     }
 
+    /// Output data rates at which the lis3dh produces samples, i.e. every rate but [`PowerDown`]. Commands that read acceleration require it, so they can't return stale data from a powered down device.
+    ///
+    /// ```compile_fail
+    /// use lis3dh_driver::bus::Lis3dhBus;
+    /// use lis3dh_driver::config::Config;
+    /// use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg};
+    /// use lis3dh_driver::Lis3dh;
+    ///
+    /// type PowerDownConfig = Config<
+    ///     ctrl_reg1::odr::PowerDown,
+    ///     ctrl_reg1::lp_en::NormalPowerMode,
+    ///     ctrl_reg1::axis_enable::XYZEnabled,
+    ///     ctrl_reg4::fs::S2G,
+    ///     ctrl_reg4::hr::NormalResolution,
+    ///     ctrl_reg4::bdu::ContinuousDataUpdate,
+    ///     ctrl_reg4::ble::LittleEndian,
+    ///     temp_cfg_reg::adc_en::AdcDisabled,
+    ///     temp_cfg_reg::temp_en::TempDisabled,
+    ///     ctrl_reg5::fifo_en::FifoDisabled,
+    ///     fifo_ctrl_reg::fm::Bypass,
+    ///     fifo_ctrl_reg::fth::Watermark<0>,
+    /// >;
+    ///
+    /// async fn read<Bus: Lis3dhBus>(lis3dh: &mut Lis3dh<Bus, PowerDownConfig>) {
+    ///     let _ = lis3dh.get_accel_vector().await;
+    /// }
+    /// ```
+    pub trait Active: State {}
+
     #[repr(u8)]
     pub enum Variant {
         PowerDown = 0b0000,
@@ -54,9 +83,13 @@ pub mod odr {
         F1344Hz = 0b1001, // Exclusive to normal power mode.
     }
 
+    crate::registers::define_field_reader!(
+        CtrlReg1, PowerDown, F1Hz, F10Hz, F25Hz, F50Hz, F100Hz, F200Hz, F400Hz, F1600Hz, F1344Hz
+    );
+
     // Special case for odr register. The raw value 0b1001 is used to set both 1344Hz in normal power mode, and 5376Hz in low power mode.
     // Entitlements will help keep this clear to the user as they can not set a power mode specific frequency without being in the correct power state.
-    // In the future, I might combine fields into a "feature" named OperatingMode that takes the entangled fields odr, lp_en, and hr to avoid this band-aid solution.
+    // The entangled fields odr, lp_en and hr are combined in `properties::operating_mode::OperatingMode`, which resolves the frequency from the power mode.
     impl Variant {
         pub const F5376HZ: Variant = Variant::F1344Hz;
     }
@@ -88,6 +121,17 @@ pub mod odr {
     impl State for F5376Hz {
         const VARIANT: Variant = Variant::F5376HZ;
     }
+
+    impl Active for F1Hz {}
+    impl Active for F10Hz {}
+    impl Active for F25Hz {}
+    impl Active for F50Hz {}
+    impl Active for F100Hz {}
+    impl Active for F200Hz {}
+    impl Active for F400Hz {}
+    impl Active for F1600Hz {}
+    impl Active for F1344Hz {}
+    impl Active for F5376Hz {}
 }
 
 // Entitlements of odr bit field.
@@ -124,6 +168,8 @@ pub mod lp_en {
         LowPowerMode = 0b1,
     }
 
+    crate::registers::define_field_reader!(CtrlReg1, NormalPowerMode, LowPowerMode);
+
     pub struct NormalPowerMode;
     pub struct LowPowerMode;
 
@@ -146,6 +192,9 @@ pub mod lp_en {
 /// - `Xen`: X-axis enable. Default value: 1.
 ///   - `0b0`: X-axis disabled.
 ///   - `0b1`: X-axis enabled
+///
+/// ### Entitlements:
+///   - [`axis_enable::XYZDisabled`] is entitled to [`odr::PowerDown`], as sampling with every axis disabled outputs nothing.
 pub mod axis_enable {
     pub const ADDR: u8 = super::ADDR;
     pub const WIDTH: u8 = 3;
@@ -168,6 +217,18 @@ pub mod axis_enable {
         XYZEnabled = 0b111,
     }
 
+    crate::registers::define_field_reader!(
+        CtrlReg1,
+        XYZDisabled,
+        XEnabled,
+        YEnabled,
+        XYEnabled,
+        ZEnabled,
+        XZEnabled,
+        YZEnabled,
+        XYZEnabled
+    );
+
     macro_rules! impls {
         ($name:ident) => {
             pub struct $name;
@@ -187,4 +248,14 @@ pub mod axis_enable {
     impls!(XYZEnabled);
 }
 
+// Entitlements of axis_enable bit field.
+impl Entitled<odr::PowerDown> for axis_enable::XYZDisabled {}
+impl<T: odr::State> Entitled<T> for axis_enable::XEnabled {}
+impl<T: odr::State> Entitled<T> for axis_enable::YEnabled {}
+impl<T: odr::State> Entitled<T> for axis_enable::XYEnabled {}
+impl<T: odr::State> Entitled<T> for axis_enable::ZEnabled {}
+impl<T: odr::State> Entitled<T> for axis_enable::XZEnabled {}
+impl<T: odr::State> Entitled<T> for axis_enable::YZEnabled {}
+impl<T: odr::State> Entitled<T> for axis_enable::XYZEnabled {}
+
 define_state_renderer!(odr, lp_en, axis_enable);
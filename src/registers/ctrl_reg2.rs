@@ -0,0 +1,246 @@
+//! # CTRL_REG2 (21h)
+//! High-pass filter configuration.
+//! ## Fields:
+//! - `hpm`: High-pass filter mode selection.
+//! - `hpcf`: High-pass filter cutoff frequency selection.
+//! - `fds`: Filtered data selection.
+//! - `hpclick`: High-pass filter enabled for CLICK function.
+//! - `hp_ia2`: High-pass filter enabled for AOI function on interrupt 2.
+//! - `hp_ia1`: High-pass filter enabled for AOI function on interrupt 1.
+
+use crate::registers::{define_state_renderer, ReadWriteRegisterAddress};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::CtrlReg2 as u8;
+
+/// ### `hpm`: High-pass filter mode selection.
+///   - `0b00`: Normal mode, reset by reading `REFERENCE (26h)`.
+///   - `0b01`: Reference signal for filtering.
+///   - `0b10`: Normal mode.
+///   - `0b11`: Autoreset on interrupt event.
+///
+/// *Default value: 0b00 (normal mode, reset by reading `REFERENCE`).*
+pub mod hpm {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 2;
+    pub const OFFSET: u8 = 6;
+    pub type Default = NormalResetByReference;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        NormalResetByReference = 0b00,
+        ReferenceSignal = 0b01,
+        Normal = 0b10,
+        AutoresetOnInterrupt = 0b11,
+    }
+
+    crate::registers::define_field_reader!(
+        CtrlReg2,
+        NormalResetByReference,
+        ReferenceSignal,
+        Normal,
+        AutoresetOnInterrupt
+    );
+
+    macro_rules! impls {
+        ($name:ident) => {
+            pub struct $name;
+
+            impl State for $name {
+                const VARIANT: Variant = Variant::$name;
+            }
+        };
+    }
+
+    impls!(NormalResetByReference);
+    impls!(ReferenceSignal);
+    impls!(Normal);
+    impls!(AutoresetOnInterrupt);
+}
+
+/// ### `hpcf`: High-pass filter cutoff frequency selection.
+///   - `0b00` to `0b11`: from the highest to the lowest cutoff frequency.
+///
+/// The cutoff frequency in Hz depends on the output data rate, see [`crate::properties::hp_cutoff_hz`].
+///
+/// *Default value: 0b00 (highest cutoff).*
+pub mod hpcf {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 2;
+    pub const OFFSET: u8 = 4;
+    pub type Default = Hpcf0;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        Hpcf0 = 0b00,
+        Hpcf1 = 0b01,
+        Hpcf2 = 0b10,
+        Hpcf3 = 0b11,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg2, Hpcf0, Hpcf1, Hpcf2, Hpcf3);
+
+    macro_rules! impls {
+        ($name:ident) => {
+            pub struct $name;
+
+            impl State for $name {
+                const VARIANT: Variant = Variant::$name;
+            }
+        };
+    }
+
+    impls!(Hpcf0);
+    impls!(Hpcf1);
+    impls!(Hpcf2);
+    impls!(Hpcf3);
+}
+
+/// ### `fds`: Filtered data selection.
+///   - `0b0`: internal filter bypassed.
+///   - `0b1`: data from the internal filter sent to the output registers and FIFO.
+///
+/// *Default value: 0 (internal filter bypassed).*
+pub mod fds {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 3;
+    pub type Default = FilterBypassed;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        FilterBypassed = 0b0,
+        FilteredData = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg2, FilterBypassed, FilteredData);
+
+    pub struct FilterBypassed;
+    pub struct FilteredData;
+
+    impl State for FilterBypassed {
+        const VARIANT: Variant = Variant::FilterBypassed;
+    }
+
+    impl State for FilteredData {
+        const VARIANT: Variant = Variant::FilteredData;
+    }
+}
+
+/// ### `hpclick`: High-pass filter enabled for CLICK function.
+///   - `0b0`: filter bypassed.
+///   - `0b1`: filter enabled.
+///
+/// *Default value: 0 (filter bypassed).*
+pub mod hpclick {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 2;
+    pub type Default = FilterBypassed;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        FilterBypassed = 0b0,
+        FilterEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg2, FilterBypassed, FilterEnabled);
+
+    pub struct FilterBypassed;
+    pub struct FilterEnabled;
+
+    impl State for FilterBypassed {
+        const VARIANT: Variant = Variant::FilterBypassed;
+    }
+
+    impl State for FilterEnabled {
+        const VARIANT: Variant = Variant::FilterEnabled;
+    }
+}
+
+/// ### `hp_ia2`: High-pass filter enabled for AOI function on interrupt 2.
+///   - `0b0`: filter bypassed.
+///   - `0b1`: filter enabled.
+///
+/// *Default value: 0 (filter bypassed).*
+pub mod hp_ia2 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 1;
+    pub type Default = FilterBypassed;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        FilterBypassed = 0b0,
+        FilterEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg2, FilterBypassed, FilterEnabled);
+
+    pub struct FilterBypassed;
+    pub struct FilterEnabled;
+
+    impl State for FilterBypassed {
+        const VARIANT: Variant = Variant::FilterBypassed;
+    }
+
+    impl State for FilterEnabled {
+        const VARIANT: Variant = Variant::FilterEnabled;
+    }
+}
+
+/// ### `hp_ia1`: High-pass filter enabled for AOI function on interrupt 1.
+///   - `0b0`: filter bypassed.
+///   - `0b1`: filter enabled.
+///
+/// *Default value: 0 (filter bypassed).*
+pub mod hp_ia1 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 0;
+    pub type Default = FilterBypassed;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        FilterBypassed = 0b0,
+        FilterEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg2, FilterBypassed, FilterEnabled);
+
+    pub struct FilterBypassed;
+    pub struct FilterEnabled;
+
+    impl State for FilterBypassed {
+        const VARIANT: Variant = Variant::FilterBypassed;
+    }
+
+    impl State for FilterEnabled {
+        const VARIANT: Variant = Variant::FilterEnabled;
+    }
+}
+
+define_state_renderer!(hpm, hpcf, fds, hpclick, hp_ia2, hp_ia1);
@@ -0,0 +1,372 @@
+//! # CTRL_REG3 (22h)
+//! Routing of interrupt sources to the INT1 pin.
+//! ## Fields:
+//! - `i1_click`: Click interrupt on INT1.
+//! - `i1_ia1`: IA1 interrupt on INT1.
+//! - `i1_ia2`: IA2 interrupt on INT1.
+//! - `i1_zyxda`: ZYXDA interrupt on INT1.
+//! - `i1_321da`: 321DA interrupt on INT1.
+//! - `i1_wtm`: FIFO watermark interrupt on INT1.
+//! - `i1_overrun`: FIFO overrun interrupt on INT1.
+
+use core::marker::PhantomData;
+
+use crate::registers::{define_state_renderer, ReadWriteRegisterAddress};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::CtrlReg3 as u8;
+
+/// ### `i1_click`: Click interrupt on INT1.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i1_click {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 7;
+    pub type Default = ClickDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        ClickDisabled = 0b0,
+        ClickEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg3, ClickDisabled, ClickEnabled);
+
+    pub struct ClickDisabled;
+    pub struct ClickEnabled;
+
+    impl State for ClickDisabled {
+        const VARIANT: Variant = Variant::ClickDisabled;
+    }
+
+    impl State for ClickEnabled {
+        const VARIANT: Variant = Variant::ClickEnabled;
+    }
+}
+
+/// ### `i1_ia1`: IA1 interrupt on INT1.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i1_ia1 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 6;
+    pub type Default = Ia1Disabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        Ia1Disabled = 0b0,
+        Ia1Enabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg3, Ia1Disabled, Ia1Enabled);
+
+    pub struct Ia1Disabled;
+    pub struct Ia1Enabled;
+
+    impl State for Ia1Disabled {
+        const VARIANT: Variant = Variant::Ia1Disabled;
+    }
+
+    impl State for Ia1Enabled {
+        const VARIANT: Variant = Variant::Ia1Enabled;
+    }
+}
+
+/// ### `i1_ia2`: IA2 interrupt on INT1.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i1_ia2 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 5;
+    pub type Default = Ia2Disabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        Ia2Disabled = 0b0,
+        Ia2Enabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg3, Ia2Disabled, Ia2Enabled);
+
+    pub struct Ia2Disabled;
+    pub struct Ia2Enabled;
+
+    impl State for Ia2Disabled {
+        const VARIANT: Variant = Variant::Ia2Disabled;
+    }
+
+    impl State for Ia2Enabled {
+        const VARIANT: Variant = Variant::Ia2Enabled;
+    }
+}
+
+/// ### `i1_zyxda`: ZYXDA interrupt on INT1.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i1_zyxda {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 4;
+    pub type Default = ZyxdaDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        ZyxdaDisabled = 0b0,
+        ZyxdaEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg3, ZyxdaDisabled, ZyxdaEnabled);
+
+    pub struct ZyxdaDisabled;
+    pub struct ZyxdaEnabled;
+
+    impl State for ZyxdaDisabled {
+        const VARIANT: Variant = Variant::ZyxdaDisabled;
+    }
+
+    impl State for ZyxdaEnabled {
+        const VARIANT: Variant = Variant::ZyxdaEnabled;
+    }
+}
+
+/// ### `i1_321da`: 321DA interrupt on INT1.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i1_321da {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 3;
+    pub type Default = Da321Disabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        Da321Disabled = 0b0,
+        Da321Enabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg3, Da321Disabled, Da321Enabled);
+
+    pub struct Da321Disabled;
+    pub struct Da321Enabled;
+
+    impl State for Da321Disabled {
+        const VARIANT: Variant = Variant::Da321Disabled;
+    }
+
+    impl State for Da321Enabled {
+        const VARIANT: Variant = Variant::Da321Enabled;
+    }
+}
+
+/// ### `i1_wtm`: FIFO watermark interrupt on INT1.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i1_wtm {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 2;
+    pub type Default = WtmDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        WtmDisabled = 0b0,
+        WtmEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg3, WtmDisabled, WtmEnabled);
+
+    pub struct WtmDisabled;
+    pub struct WtmEnabled;
+
+    impl State for WtmDisabled {
+        const VARIANT: Variant = Variant::WtmDisabled;
+    }
+
+    impl State for WtmEnabled {
+        const VARIANT: Variant = Variant::WtmEnabled;
+    }
+}
+
+/// ### `i1_overrun`: FIFO overrun interrupt on INT1.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i1_overrun {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 1;
+    pub type Default = OverrunDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        OverrunDisabled = 0b0,
+        OverrunEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg3, OverrunDisabled, OverrunEnabled);
+
+    pub struct OverrunDisabled;
+    pub struct OverrunEnabled;
+
+    impl State for OverrunDisabled {
+        const VARIANT: Variant = Variant::OverrunDisabled;
+    }
+
+    impl State for OverrunEnabled {
+        const VARIANT: Variant = Variant::OverrunEnabled;
+    }
+}
+
+define_state_renderer!(i1_click, i1_ia1, i1_ia2, i1_zyxda, i1_321da, i1_wtm, i1_overrun);
+
+/// Builder of the interrupt sources routed to the INT1 pin, written to `CTRL_REG3` by [`crate::Lis3dh::configure_int1_routing`].
+///
+/// Every source starts disabled and each method routes one more source, e.g. `Int1Routing::new().click().zyxda()`.
+pub struct Int1Routing<Click, Ia1, Ia2, Zyxda, Da321, Wtm, Overrun>
+where
+    Click: i1_click::State,
+    Ia1: i1_ia1::State,
+    Ia2: i1_ia2::State,
+    Zyxda: i1_zyxda::State,
+    Da321: i1_321da::State,
+    Wtm: i1_wtm::State,
+    Overrun: i1_overrun::State,
+{
+    _p: PhantomData<(Click, Ia1, Ia2, Zyxda, Da321, Wtm, Overrun)>,
+}
+
+impl
+    Int1Routing<
+        i1_click::ClickDisabled,
+        i1_ia1::Ia1Disabled,
+        i1_ia2::Ia2Disabled,
+        i1_zyxda::ZyxdaDisabled,
+        i1_321da::Da321Disabled,
+        i1_wtm::WtmDisabled,
+        i1_overrun::OverrunDisabled,
+    >
+{
+    /// Returns a routing with every interrupt source disabled.
+    pub fn new() -> Self {
+        Int1Routing { _p: PhantomData }
+    }
+}
+
+impl Default
+    for Int1Routing<
+        i1_click::ClickDisabled,
+        i1_ia1::Ia1Disabled,
+        i1_ia2::Ia2Disabled,
+        i1_zyxda::ZyxdaDisabled,
+        i1_321da::Da321Disabled,
+        i1_wtm::WtmDisabled,
+        i1_overrun::OverrunDisabled,
+    >
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Click, Ia1, Ia2, Zyxda, Da321, Wtm, Overrun>
+    Int1Routing<Click, Ia1, Ia2, Zyxda, Da321, Wtm, Overrun>
+where
+    Click: i1_click::State,
+    Ia1: i1_ia1::State,
+    Ia2: i1_ia2::State,
+    Zyxda: i1_zyxda::State,
+    Da321: i1_321da::State,
+    Wtm: i1_wtm::State,
+    Overrun: i1_overrun::State,
+{
+    /// Routes the click interrupt to INT1.
+    pub fn click(
+        self,
+    ) -> Int1Routing<i1_click::ClickEnabled, Ia1, Ia2, Zyxda, Da321, Wtm, Overrun> {
+        Int1Routing { _p: PhantomData }
+    }
+
+    /// Routes the IA1 interrupt to INT1.
+    pub fn ia1(self) -> Int1Routing<Click, i1_ia1::Ia1Enabled, Ia2, Zyxda, Da321, Wtm, Overrun> {
+        Int1Routing { _p: PhantomData }
+    }
+
+    /// Routes the IA2 interrupt to INT1.
+    pub fn ia2(self) -> Int1Routing<Click, Ia1, i1_ia2::Ia2Enabled, Zyxda, Da321, Wtm, Overrun> {
+        Int1Routing { _p: PhantomData }
+    }
+
+    /// Routes the ZYXDA interrupt to INT1.
+    pub fn zyxda(
+        self,
+    ) -> Int1Routing<Click, Ia1, Ia2, i1_zyxda::ZyxdaEnabled, Da321, Wtm, Overrun> {
+        Int1Routing { _p: PhantomData }
+    }
+
+    /// Routes the 321DA interrupt to INT1.
+    pub fn da321(
+        self,
+    ) -> Int1Routing<Click, Ia1, Ia2, Zyxda, i1_321da::Da321Enabled, Wtm, Overrun> {
+        Int1Routing { _p: PhantomData }
+    }
+
+    /// Routes the FIFO watermark interrupt to INT1.
+    pub fn wtm(self) -> Int1Routing<Click, Ia1, Ia2, Zyxda, Da321, i1_wtm::WtmEnabled, Overrun> {
+        Int1Routing { _p: PhantomData }
+    }
+
+    /// Routes the FIFO overrun interrupt to INT1.
+    pub fn overrun(
+        self,
+    ) -> Int1Routing<Click, Ia1, Ia2, Zyxda, Da321, Wtm, i1_overrun::OverrunEnabled> {
+        Int1Routing { _p: PhantomData }
+    }
+
+    /// Renders the `CTRL_REG3` register byte.
+    pub(crate) fn render(&self) -> u8 {
+        render_hardware_state::<Click, Ia1, Ia2, Zyxda, Da321, Wtm, Overrun>()
+    }
+}
@@ -32,6 +32,8 @@ pub mod bdu {
         BlockDataUpdate = 0b1,
     }
 
+    crate::registers::define_field_reader!(CtrlReg4, ContinuousDataUpdate, BlockDataUpdate);
+
     pub struct ContinuousDataUpdate;
     pub struct BlockDataUpdate;
 
@@ -68,6 +70,8 @@ pub mod ble {
         BigEndian = 0b1,
     }
 
+    crate::registers::define_field_reader!(CtrlReg4, LittleEndian, BigEndian);
+
     pub struct BigEndian;
     pub struct LittleEndian;
 
@@ -81,6 +85,7 @@ pub mod ble {
 }
 
 // Entitlements for ble bit-field
+impl<T: hr::State> Entitled<T> for ble::LittleEndian {}
 impl Entitled<hr::HighResolution> for ble::BigEndian {}
 
 /// ### `fs`: Full-scale selection.
@@ -108,6 +113,8 @@ pub mod fs {
         S16G = 0b11,
     }
 
+    crate::registers::define_field_reader!(CtrlReg4, S2G, S4G, S8G, S16G);
+
     macro_rules! impls {
         ($name:ident) => {
             pub struct $name;
@@ -148,6 +155,8 @@ pub mod hr {
         HighResolution = 0b1,
     }
 
+    crate::registers::define_field_reader!(CtrlReg4, NormalResolution, HighResolution);
+
     pub struct NormalResolution;
     pub struct HighResolution;
 
@@ -188,6 +197,8 @@ pub mod st {
         SelfTest1 = 0b10,
     }
 
+    crate::registers::define_field_reader!(CtrlReg4, NormalMode, SelfTest0, SelfTest1);
+
     pub struct NormalMode;
     pub struct SelfTest0;
     pub struct SelfTest1;
@@ -225,6 +236,8 @@ pub mod sim {
         Spi4Wire = 0b0,
         Spi3Wire = 0b1,
     }
+
+    crate::registers::define_field_reader!(CtrlReg4, Spi4Wire, Spi3Wire);
     pub struct Spi4Wire;
     pub struct Spi3Wire;
 
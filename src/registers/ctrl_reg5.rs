@@ -0,0 +1,226 @@
+//! # CTRL_REG5 (24h)
+//! ## Fields:
+//! - `boot`: Reboot memory content.
+//! - `fifo_en`: FIFO enable.
+//! - `lir_int1`: Latch interrupt request on INT1_SRC, cleared by reading INT1_SRC.
+//! - `d4d_int1`: 4D detection on INT1 when 6D is enabled in INT1_CFG.
+//! - `lir_int2`: Latch interrupt request on INT2_SRC, cleared by reading INT2_SRC.
+//! - `d4d_int2`: 4D detection on INT2 when 6D is enabled in INT2_CFG.
+
+use crate::registers::{define_state_renderer, ReadWriteRegisterAddress};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::CtrlReg5 as u8;
+
+/// ### `boot`: Reboot memory content.
+///   - `0b0`: normal mode.
+///   - `0b1`: reboot memory content.
+///
+/// The bit clears itself once the trimming parameters have been reloaded.
+///
+/// *Default value: 0 (normal mode).*
+pub mod boot {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 7;
+    pub type Default = NormalMode;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        NormalMode = 0b0,
+        RebootMemoryContent = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg5, NormalMode, RebootMemoryContent);
+
+    pub struct NormalMode;
+    pub struct RebootMemoryContent;
+
+    impl State for NormalMode {
+        const VARIANT: Variant = Variant::NormalMode;
+    }
+
+    impl State for RebootMemoryContent {
+        const VARIANT: Variant = Variant::RebootMemoryContent;
+    }
+}
+
+/// ### `fifo_en`: FIFO enable.
+///   - `0b0`: FIFO disabled.
+///   - `0b1`: FIFO enabled.
+///
+/// *Default value: 0 (FIFO disabled).*
+pub mod fifo_en {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 6;
+    pub type Default = FifoDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        FifoDisabled = 0b0,
+        FifoEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg5, FifoDisabled, FifoEnabled);
+
+    pub struct FifoDisabled;
+    pub struct FifoEnabled;
+
+    impl State for FifoDisabled {
+        const VARIANT: Variant = Variant::FifoDisabled;
+    }
+
+    impl State for FifoEnabled {
+        const VARIANT: Variant = Variant::FifoEnabled;
+    }
+}
+
+/// ### `lir_int1`: Latch interrupt request on INT1_SRC.
+///   - `0b0`: interrupt request not latched.
+///   - `0b1`: interrupt request latched until INT1_SRC is read.
+///
+/// *Default value: 0 (not latched).*
+pub mod lir_int1 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 3;
+    pub type Default = NotLatched;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        NotLatched = 0b0,
+        Latched = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg5, NotLatched, Latched);
+
+    pub struct NotLatched;
+    pub struct Latched;
+
+    impl State for NotLatched {
+        const VARIANT: Variant = Variant::NotLatched;
+    }
+
+    impl State for Latched {
+        const VARIANT: Variant = Variant::Latched;
+    }
+}
+
+/// ### `d4d_int1`: 4D detection on INT1.
+///   - `0b0`: 4D detection disabled.
+///   - `0b1`: 4D detection enabled on INT1 when 6D is enabled in INT1_CFG, ignoring the Z axis.
+///
+/// *Default value: 0 (disabled).*
+pub mod d4d_int1 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 2;
+    pub type Default = D4dDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        D4dDisabled = 0b0,
+        D4dEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg5, D4dDisabled, D4dEnabled);
+
+    pub struct D4dDisabled;
+    pub struct D4dEnabled;
+
+    impl State for D4dDisabled {
+        const VARIANT: Variant = Variant::D4dDisabled;
+    }
+
+    impl State for D4dEnabled {
+        const VARIANT: Variant = Variant::D4dEnabled;
+    }
+}
+
+/// ### `lir_int2`: Latch interrupt request on INT2_SRC.
+///   - `0b0`: interrupt request not latched.
+///   - `0b1`: interrupt request latched until INT2_SRC is read.
+///
+/// *Default value: 0 (not latched).*
+pub mod lir_int2 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 1;
+    pub type Default = NotLatched;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        NotLatched = 0b0,
+        Latched = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg5, NotLatched, Latched);
+
+    pub struct NotLatched;
+    pub struct Latched;
+
+    impl State for NotLatched {
+        const VARIANT: Variant = Variant::NotLatched;
+    }
+
+    impl State for Latched {
+        const VARIANT: Variant = Variant::Latched;
+    }
+}
+
+/// ### `d4d_int2`: 4D detection on INT2.
+///   - `0b0`: 4D detection disabled.
+///   - `0b1`: 4D detection enabled on INT2 when 6D is enabled in INT2_CFG, ignoring the Z axis.
+///
+/// *Default value: 0 (disabled).*
+pub mod d4d_int2 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 0;
+    pub type Default = D4dDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        D4dDisabled = 0b0,
+        D4dEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg5, D4dDisabled, D4dEnabled);
+
+    pub struct D4dDisabled;
+    pub struct D4dEnabled;
+
+    impl State for D4dDisabled {
+        const VARIANT: Variant = Variant::D4dDisabled;
+    }
+
+    impl State for D4dEnabled {
+        const VARIANT: Variant = Variant::D4dEnabled;
+    }
+}
+
+define_state_renderer!(boot, fifo_en, lir_int1, d4d_int1, lir_int2, d4d_int2);
@@ -0,0 +1,225 @@
+//! # CTRL_REG6 (25h)
+//! Routing of interrupt sources to the INT2 pin, and the polarity of both interrupt pins.
+//! ## Fields:
+//! - `i2_click`: Click interrupt on INT2.
+//! - `i2_ia1`: IA1 interrupt on INT2.
+//! - `i2_ia2`: IA2 interrupt on INT2.
+//! - `i2_boot`: Boot on INT2.
+//! - `i2_act`: Activity interrupt on INT2.
+//! - `int_polarity`: Interrupt active-high (`0`) or active-low (`1`), for both INT1 and INT2.
+
+use crate::registers::{define_state_renderer, ReadWriteRegisterAddress};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::CtrlReg6 as u8;
+
+/// ### `i2_click`: Click interrupt on INT2.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i2_click {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 7;
+    pub type Default = ClickDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        ClickDisabled = 0b0,
+        ClickEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg6, ClickDisabled, ClickEnabled);
+
+    pub struct ClickDisabled;
+    pub struct ClickEnabled;
+
+    impl State for ClickDisabled {
+        const VARIANT: Variant = Variant::ClickDisabled;
+    }
+
+    impl State for ClickEnabled {
+        const VARIANT: Variant = Variant::ClickEnabled;
+    }
+}
+
+/// ### `i2_ia1`: IA1 interrupt on INT2.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i2_ia1 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 6;
+    pub type Default = Ia1Disabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        Ia1Disabled = 0b0,
+        Ia1Enabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg6, Ia1Disabled, Ia1Enabled);
+
+    pub struct Ia1Disabled;
+    pub struct Ia1Enabled;
+
+    impl State for Ia1Disabled {
+        const VARIANT: Variant = Variant::Ia1Disabled;
+    }
+
+    impl State for Ia1Enabled {
+        const VARIANT: Variant = Variant::Ia1Enabled;
+    }
+}
+
+/// ### `i2_ia2`: IA2 interrupt on INT2.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i2_ia2 {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 5;
+    pub type Default = Ia2Disabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        Ia2Disabled = 0b0,
+        Ia2Enabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg6, Ia2Disabled, Ia2Enabled);
+
+    pub struct Ia2Disabled;
+    pub struct Ia2Enabled;
+
+    impl State for Ia2Disabled {
+        const VARIANT: Variant = Variant::Ia2Disabled;
+    }
+
+    impl State for Ia2Enabled {
+        const VARIANT: Variant = Variant::Ia2Enabled;
+    }
+}
+
+/// ### `i2_boot`: Boot on INT2.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i2_boot {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 4;
+    pub type Default = BootDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        BootDisabled = 0b0,
+        BootEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg6, BootDisabled, BootEnabled);
+
+    pub struct BootDisabled;
+    pub struct BootEnabled;
+
+    impl State for BootDisabled {
+        const VARIANT: Variant = Variant::BootDisabled;
+    }
+
+    impl State for BootEnabled {
+        const VARIANT: Variant = Variant::BootEnabled;
+    }
+}
+
+/// ### `i2_act`: Activity interrupt on INT2.
+///   - `0b0`: disabled.
+///   - `0b1`: enabled.
+///
+/// *Default value: 0 (disabled).*
+pub mod i2_act {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 3;
+    pub type Default = ActDisabled;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        ActDisabled = 0b0,
+        ActEnabled = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg6, ActDisabled, ActEnabled);
+
+    pub struct ActDisabled;
+    pub struct ActEnabled;
+
+    impl State for ActDisabled {
+        const VARIANT: Variant = Variant::ActDisabled;
+    }
+
+    impl State for ActEnabled {
+        const VARIANT: Variant = Variant::ActEnabled;
+    }
+}
+
+/// ### `int_polarity`: Interrupt active level, for both INT1 and INT2.
+///   - `0b0`: active-high.
+///   - `0b1`: active-low.
+///
+/// *Default value: 0 (active-high).*
+pub mod int_polarity {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 1;
+    pub type Default = ActiveHigh;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        ActiveHigh = 0b0,
+        ActiveLow = 0b1,
+    }
+
+    crate::registers::define_field_reader!(CtrlReg6, ActiveHigh, ActiveLow);
+
+    pub struct ActiveHigh;
+    pub struct ActiveLow;
+
+    impl State for ActiveHigh {
+        const VARIANT: Variant = Variant::ActiveHigh;
+    }
+
+    impl State for ActiveLow {
+        const VARIANT: Variant = Variant::ActiveLow;
+    }
+}
+
+define_state_renderer!(i2_click, i2_ia1, i2_ia2, i2_boot, i2_act, int_polarity);
@@ -0,0 +1,164 @@
+//! # FIFO_CTRL_REG (2Eh)
+//! ## Fields:
+//! - `fm`: FIFO mode selection.
+//! - `tr`: Trigger selection.
+//! - `fth`: FIFO watermark threshold.
+
+use crate::registers::{
+    ctrl_reg5::fifo_en, define_state_renderer, Entitled, ReadWriteRegisterAddress,
+};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::FifoCtrlReg as u8;
+
+/// ### `fm`: FIFO mode selection.
+///   - `0b00`: Bypass mode.
+///   - `0b01`: FIFO mode.
+///   - `0b10`: Stream mode.
+///   - `0b11`: Stream-to-FIFO mode.
+///
+/// *Default value: 0b00 (Bypass).*
+///
+/// ### Entitlements:
+///   - [`fm::Fifo`], [`fm::Stream`] and [`fm::StreamToFifo`] are entitled to [`fifo_en::FifoEnabled`].
+pub mod fm {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 2;
+    pub const OFFSET: u8 = 6;
+    pub type Default = Bypass;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        Bypass = 0b00,
+        Fifo = 0b01,
+        Stream = 0b10,
+        StreamToFifo = 0b11,
+    }
+
+    crate::registers::define_field_reader!(FifoCtrlReg, Bypass, Fifo, Stream, StreamToFifo);
+
+    macro_rules! impls {
+        ($name:ident) => {
+            pub struct $name;
+
+            impl State for $name {
+                const VARIANT: Variant = Variant::$name;
+            }
+        };
+    }
+
+    impls!(Bypass);
+    impls!(Fifo);
+    impls!(Stream);
+    impls!(StreamToFifo);
+}
+
+// Entitlements of fm bit field.
+impl<T: fifo_en::State> Entitled<T> for fm::Bypass {}
+impl Entitled<fifo_en::FifoEnabled> for fm::Fifo {}
+impl Entitled<fifo_en::FifoEnabled> for fm::Stream {}
+impl Entitled<fifo_en::FifoEnabled> for fm::StreamToFifo {}
+
+/// ### `tr`: Trigger selection.
+///   - `0b0`: Trigger event linked to interrupt generator 1.
+///   - `0b1`: Trigger event linked to interrupt generator 2.
+///
+/// *Default value: 0 (interrupt generator 1).*
+///
+/// ### Entitlements:
+///   - [`tr::Int2`] is entitled to [`fm::StreamToFifo`], the only mode with a trigger event. [`tr::Int1`] is the reset value and entitled to every mode.
+pub mod tr {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 1;
+    pub const OFFSET: u8 = 5;
+    pub type Default = Int1;
+
+    pub trait State {
+        const VARIANT: Variant;
+    }
+
+    #[repr(u8)]
+    pub enum Variant {
+        Int1 = 0b0,
+        Int2 = 0b1,
+    }
+
+    crate::registers::define_field_reader!(FifoCtrlReg, Int1, Int2);
+
+    pub struct Int1;
+    pub struct Int2;
+
+    impl State for Int1 {
+        const VARIANT: Variant = Variant::Int1;
+    }
+
+    impl State for Int2 {
+        const VARIANT: Variant = Variant::Int2;
+    }
+}
+
+// Entitlements of tr bit field.
+impl<T: fm::State> Entitled<T> for tr::Int1 {}
+impl Entitled<fm::StreamToFifo> for tr::Int2 {}
+
+/// ### `fth`: FIFO watermark threshold.
+///   - `0` to `31`: number of FIFO samples at which the watermark flag is raised.
+///
+/// *Default value: 0.*
+///
+/// Every raw value of the 5-bit field is valid, so rather than a `Variant` enum the type-state [`fth::Watermark`] carries the raw level as a const generic.
+pub mod fth {
+    pub const ADDR: u8 = super::ADDR;
+    pub const WIDTH: u8 = 5;
+    pub const OFFSET: u8 = 0;
+    pub type Default = Watermark<0>;
+
+    pub trait State {
+        const VARIANT: u8;
+    }
+
+    /// Watermark of `LEVEL` samples. Levels above 31 fail to compile once rendered.
+    pub struct Watermark<const LEVEL: u8>;
+
+    impl<const LEVEL: u8> State for Watermark<LEVEL> {
+        const VARIANT: u8 = {
+            assert!(LEVEL < 1 << WIDTH, "FIFO watermark must be in 0..=31");
+            LEVEL
+        };
+    }
+
+    /// Marker for reading the raw watermark level back, see [`crate::Lis3dh::read_field`].
+    pub struct Field;
+
+    impl crate::registers::Field for Field {
+        const REGISTER: crate::registers::ReadWriteRegisterAddress =
+            crate::registers::ReadWriteRegisterAddress::FifoCtrlReg;
+        const WIDTH: u8 = WIDTH;
+        const OFFSET: u8 = OFFSET;
+        type Variant = u8;
+    }
+}
+
+/// Interrupt generator whose event triggers the switch from stream to FIFO mode in [`fm::StreamToFifo`], see [`tr`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FifoTrigger {
+    /// Interrupt generator 1, configured through `INT1_CFG (30h)`.
+    Int1,
+    /// Interrupt generator 2, configured through `INT2_CFG (34h)`.
+    Int2,
+}
+
+impl From<FifoTrigger> for tr::Variant {
+    fn from(trigger: FifoTrigger) -> Self {
+        match trigger {
+            FifoTrigger::Int1 => tr::Variant::Int1,
+            FifoTrigger::Int2 => tr::Variant::Int2,
+        }
+    }
+}
+
+define_state_renderer!(fm, tr, fth);
@@ -0,0 +1,57 @@
+//! # FIFO_SRC_REG (2Fh)
+//! ## Fields:
+//! - `wtm`: FIFO content exceeds the watermark level.
+//! - `ovrn_fifo`: FIFO is full and at least one sample has been overwritten.
+//! - `empty`: FIFO is empty.
+//! - `fss`: Number of unread samples in the FIFO.
+
+use crate::registers::ReadOnlyRegisterAddress;
+
+pub const ADDR: u8 = ReadOnlyRegisterAddress::FifoSrcReg as u8;
+
+pub const WTM_OFFSET: u8 = 7;
+pub const OVRN_FIFO_OFFSET: u8 = 6;
+pub const EMPTY_OFFSET: u8 = 5;
+pub const FSS_OFFSET: u8 = 0;
+pub const FSS_WIDTH: u8 = 5;
+
+/// Number of samples the FIFO holds when full.
+pub const FIFO_DEPTH: u8 = 32;
+
+/// Decoded `FIFO_SRC_REG`.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FifoStatus {
+    /// `WTM`: the FIFO holds more samples than the configured watermark.
+    pub watermark: bool,
+    /// `OVRN_FIFO`: the FIFO is full and the oldest samples are being overwritten.
+    pub overrun: bool,
+    /// `EMPTY`: every sample has been read.
+    pub empty: bool,
+    /// `FSS`: number of unread samples, 0 to 31.
+    pub sample_count: u8,
+}
+
+impl FifoStatus {
+    /// Number of samples waiting to be read. `FSS` can't express a full FIFO, so an overrun FIFO reports [`FIFO_DEPTH`].
+    pub fn unread_samples(&self) -> u8 {
+        if self.empty {
+            0
+        } else if self.overrun {
+            FIFO_DEPTH
+        } else {
+            self.sample_count
+        }
+    }
+}
+
+impl From<u8> for FifoStatus {
+    fn from(value: u8) -> Self {
+        FifoStatus {
+            watermark: value & (1 << WTM_OFFSET) != 0,
+            overrun: value & (1 << OVRN_FIFO_OFFSET) != 0,
+            empty: value & (1 << EMPTY_OFFSET) != 0,
+            sample_count: (value >> FSS_OFFSET) & ((1 << FSS_WIDTH) - 1),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! # INT1_CFG (30h)
+//! ## Fields:
+//! - `aoi`: And/Or combination of interrupt events.
+//! - `6d`: 6 direction detection function enable.
+//! - `zhie`: Enable interrupt generation on Z high event or on direction recognition.
+//! - `zlie`: Enable interrupt generation on Z low event or on direction recognition.
+//! - `yhie`: Enable interrupt generation on Y high event or on direction recognition.
+//! - `ylie`: Enable interrupt generation on Y low event or on direction recognition.
+//! - `xhie`: Enable interrupt generation on X high event or on direction recognition.
+//! - `xlie`: Enable interrupt generation on X low event or on direction recognition.
+//!
+//! | `aoi` | `6d` | Interrupt mode                       |
+//! |:-----:|:----:|--------------------------------------|
+//! | 0     | 0    | OR combination of interrupt events   |
+//! | 0     | 1    | 6 direction movement recognition     |
+//! | 1     | 0    | AND combination of interrupt events  |
+//! | 1     | 1    | 6 direction position recognition     |
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::Int1Cfg as u8;
+
+pub const AOI_OFFSET: u8 = 7;
+pub const D6_OFFSET: u8 = 6;
+pub const ZHIE_OFFSET: u8 = 5;
+pub const ZLIE_OFFSET: u8 = 4;
+pub const YHIE_OFFSET: u8 = 3;
+pub const YLIE_OFFSET: u8 = 2;
+pub const XHIE_OFFSET: u8 = 1;
+pub const XLIE_OFFSET: u8 = 0;
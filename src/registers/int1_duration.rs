@@ -0,0 +1,21 @@
+//! # INT1_DURATION (33h)
+//! ## Fields:
+//! - `d`: Minimum duration of the interrupt 1 event to be recognized, 1 LSb = 1/ODR.
+
+use crate::properties::odr_hz;
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::Int1Duration as u8;
+
+pub const D_OFFSET: u8 = 0;
+pub const D_WIDTH: u8 = 7;
+pub const D_MAX: u8 = (1 << D_WIDTH) - 1;
+
+/// Converts a duration in milliseconds to the nearest `D` count at the output data rate `Hz`, where 1 LSb = 1/ODR.
+///
+/// Durations longer than the register can express saturate at 127.
+pub fn counts_from_ms<Hz: odr_hz::Property>(duration_ms: u32) -> u8 {
+    let samples = duration_ms as f32 * Hz::FREQUENCY_HZ / 1000.0;
+    // Float to int `as` casts saturate, which clamps large counts to 255 before the 7-bit clamp.
+    ((samples + 0.5) as u8).min(D_MAX)
+}
@@ -0,0 +1,81 @@
+//! # INT1_SRC (31h)
+//! ## Fields:
+//! - `ia`: One or more interrupts have been generated.
+//! - `zh`: Z high event has occurred.
+//! - `zl`: Z low event has occurred.
+//! - `yh`: Y high event has occurred.
+//! - `yl`: Y low event has occurred.
+//! - `xh`: X high event has occurred.
+//! - `xl`: X low event has occurred.
+
+use crate::registers::ReadOnlyRegisterAddress;
+
+pub const ADDR: u8 = ReadOnlyRegisterAddress::Int1Src as u8;
+
+pub const IA_OFFSET: u8 = 6;
+pub const ZH_OFFSET: u8 = 5;
+pub const ZL_OFFSET: u8 = 4;
+pub const YH_OFFSET: u8 = 3;
+pub const YL_OFFSET: u8 = 2;
+pub const XH_OFFSET: u8 = 1;
+pub const XL_OFFSET: u8 = 0;
+
+/// Decoded interrupt source register.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InterruptSource {
+    /// `IA`: one or more interrupts have been generated.
+    pub active: bool,
+    pub z_high: bool,
+    pub z_low: bool,
+    pub y_high: bool,
+    pub y_low: bool,
+    pub x_high: bool,
+    pub x_low: bool,
+}
+
+impl From<u8> for InterruptSource {
+    fn from(value: u8) -> Self {
+        let is_set = |offset: u8| value & (1 << offset) != 0;
+        InterruptSource {
+            active: is_set(IA_OFFSET),
+            z_high: is_set(ZH_OFFSET),
+            z_low: is_set(ZL_OFFSET),
+            y_high: is_set(YH_OFFSET),
+            y_low: is_set(YL_OFFSET),
+            x_high: is_set(XH_OFFSET),
+            x_low: is_set(XL_OFFSET),
+        }
+    }
+}
+
+/// Face of the lis3dh pointing up, as recognized by 6D position detection.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Face {
+    XUp,
+    XDown,
+    YUp,
+    YDown,
+    ZUp,
+    ZDown,
+}
+
+impl InterruptSource {
+    /// Decodes the recognized position when interrupt 1 is in 6D position recognition mode, where exactly one high or low event is set. Returns `None` if no position is recognized.
+    pub fn face(&self) -> Option<Face> {
+        if !self.active {
+            return None;
+        }
+        [
+            (self.x_high, Face::XUp),
+            (self.x_low, Face::XDown),
+            (self.y_high, Face::YUp),
+            (self.y_low, Face::YDown),
+            (self.z_high, Face::ZUp),
+            (self.z_low, Face::ZDown),
+        ]
+        .into_iter()
+        .find_map(|(event, face)| event.then_some(face))
+    }
+}
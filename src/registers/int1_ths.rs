@@ -0,0 +1,11 @@
+//! # INT1_THS (32h)
+//! ## Fields:
+//! - `ths`: Interrupt 1 threshold, see [`crate::properties::threshold_coefficient`] for the value of 1 LSb.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::Int1Ths as u8;
+
+pub const THS_OFFSET: u8 = 0;
+pub const THS_WIDTH: u8 = 7;
+pub const THS_MAX: u8 = (1 << THS_WIDTH) - 1;
@@ -0,0 +1,80 @@
+//! # INT2_CFG (34h)
+//! Same fields as [`super::int1_cfg`], for interrupt generator 2.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub use crate::registers::int1_cfg::{
+    AOI_OFFSET, D6_OFFSET, XHIE_OFFSET, XLIE_OFFSET, YHIE_OFFSET, YLIE_OFFSET, ZHIE_OFFSET,
+    ZLIE_OFFSET,
+};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::Int2Cfg as u8;
+
+/// How the enabled events are combined, selected by the `aoi` and `6d` bits.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum InterruptMode {
+    /// The interrupt fires when any enabled event occurs.
+    #[default]
+    OrCombination,
+    /// The interrupt fires when all enabled events occur at once.
+    AndCombination,
+    /// The interrupt fires when the lis3dh moves into a known direction.
+    Movement6d,
+    /// The interrupt fires while the lis3dh stays in a known direction.
+    Position6d,
+}
+
+/// Axis events that generate the interrupt.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct InterruptEvents {
+    pub x_low: bool,
+    pub x_high: bool,
+    pub y_low: bool,
+    pub y_high: bool,
+    pub z_low: bool,
+    pub z_high: bool,
+}
+
+/// Interrupt generator 2 configuration, written to `INT2_CFG (0x34)`, `INT2_THS (0x36)` and `INT2_DURATION (0x37)` by [`crate::Lis3dh::configure_int2`].
+///
+/// Interrupt generator 2 runs independently of interrupt generator 1, e.g. to detect wake-on-motion while interrupt 1 detects free-fall.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Int2Config {
+    pub mode: InterruptMode,
+    pub events: InterruptEvents,
+    /// Threshold in milli-g, converted to the nearest count of the configured full scale (see [`crate::properties::threshold_coefficient`]). Saturates at 127 counts.
+    pub threshold_mg: u16,
+    /// Minimum duration of the event in samples (1 LSb = 1/ODR), 0 to 127. Larger values saturate at 127.
+    pub duration_samples: u8,
+}
+
+impl Int2Config {
+    /// Renders the `INT2_CFG` register byte.
+    pub(crate) fn int2_cfg_byte(&self) -> u8 {
+        let (aoi, d6) = match self.mode {
+            InterruptMode::OrCombination => (false, false),
+            InterruptMode::AndCombination => (true, false),
+            InterruptMode::Movement6d => (false, true),
+            InterruptMode::Position6d => (true, true),
+        };
+        let InterruptEvents {
+            x_low,
+            x_high,
+            y_low,
+            y_high,
+            z_low,
+            z_high,
+        } = self.events;
+        (aoi as u8) << AOI_OFFSET
+            | (d6 as u8) << D6_OFFSET
+            | (z_high as u8) << ZHIE_OFFSET
+            | (z_low as u8) << ZLIE_OFFSET
+            | (y_high as u8) << YHIE_OFFSET
+            | (y_low as u8) << YLIE_OFFSET
+            | (x_high as u8) << XHIE_OFFSET
+            | (x_low as u8) << XLIE_OFFSET
+    }
+}
@@ -0,0 +1,9 @@
+//! # INT2_DURATION (37h)
+//! ## Fields:
+//! - `d`: Minimum duration of the interrupt 2 event to be recognized, 1 LSb = 1/ODR.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub use crate::registers::int1_duration::{counts_from_ms, D_MAX, D_OFFSET, D_WIDTH};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::Int2Duration as u8;
@@ -0,0 +1,8 @@
+//! # INT2_SRC (35h)
+//! Same fields as [`super::int1_src`], for interrupt generator 2, so it decodes into the shared [`InterruptSource`].
+
+use crate::registers::ReadOnlyRegisterAddress;
+
+pub use crate::registers::int1_src::InterruptSource;
+
+pub const ADDR: u8 = ReadOnlyRegisterAddress::Int2Src as u8;
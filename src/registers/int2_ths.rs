@@ -0,0 +1,9 @@
+//! # INT2_THS (36h)
+//! ## Fields:
+//! - `ths`: Interrupt 2 threshold, see [`crate::properties::threshold_coefficient`] for the value of 1 LSb.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub use crate::registers::int1_ths::{THS_MAX, THS_OFFSET, THS_WIDTH};
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::Int2Ths as u8;
@@ -0,0 +1,43 @@
+//! # OUT_ADC1_L (08h) to OUT_ADC3_H (0Dh)
+//! Output of the auxiliary ADC channels. Each channel is a left-justified pair of registers, 8-bit in low power mode and 10-bit otherwise.
+//!
+//! Channel 3 outputs the temperature sensor instead when [`crate::registers::temp_cfg_reg::temp_en`] is enabled.
+
+use crate::properties::resolution;
+use crate::registers::ReadOnlyRegisterAddress;
+
+/// Voltage the ADC reads as 0, in millivolts.
+pub const ADC_CENTER_MV: i32 = 1200;
+/// Difference between the edges of the ADC input range (800 mV to 1600 mV) and its center, in millivolts.
+pub const ADC_HALF_RANGE_MV: i32 = 400;
+
+/// Auxiliary ADC input channel.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AdcChannel {
+    One,
+    Two,
+    Three,
+}
+
+impl AdcChannel {
+    /// Address of the channel's lower output register.
+    pub fn low_address(self) -> ReadOnlyRegisterAddress {
+        match self {
+            AdcChannel::One => ReadOnlyRegisterAddress::OutAdc1L,
+            AdcChannel::Two => ReadOnlyRegisterAddress::OutAdc2L,
+            AdcChannel::Three => ReadOnlyRegisterAddress::OutAdc3L,
+        }
+    }
+}
+
+/// Converts a right-justified ADC count at resolution `R` to millivolts, using the nominal 800 mV to 1600 mV input range.
+///
+/// The conversion is linear around [`ADC_CENTER_MV`]; the actual offset and gain vary between parts, so calibrate against a known voltage when accuracy matters.
+pub fn counts_to_millivolts<R: resolution::Property>(counts: i16) -> i32 {
+    let half_scale = match R::VARIANT {
+        resolution::Variant::R8Bit => 1 << 7,
+        resolution::Variant::R10Bit | resolution::Variant::R12Bit => 1 << 9,
+    };
+    ADC_CENTER_MV + counts as i32 * ADC_HALF_RANGE_MV / half_scale
+}
@@ -0,0 +1,57 @@
+//! # STATUS_REG (27h)
+//! ## Fields:
+//! - `zyxor`: X, Y and Z-axis data overrun.
+//! - `zor`: Z-axis data overrun.
+//! - `yor`: Y-axis data overrun.
+//! - `xor`: X-axis data overrun.
+//! - `zyxda`: X, Y and Z-axis new data available.
+//! - `zda`: Z-axis new data available.
+//! - `yda`: Y-axis new data available.
+//! - `xda`: X-axis new data available.
+
+use crate::registers::ReadOnlyRegisterAddress;
+
+pub const ADDR: u8 = ReadOnlyRegisterAddress::StatusReg as u8;
+
+pub const ZYXOR_OFFSET: u8 = 7;
+pub const ZOR_OFFSET: u8 = 6;
+pub const YOR_OFFSET: u8 = 5;
+pub const XOR_OFFSET: u8 = 4;
+pub const ZYXDA_OFFSET: u8 = 3;
+pub const ZDA_OFFSET: u8 = 2;
+pub const YDA_OFFSET: u8 = 1;
+pub const XDA_OFFSET: u8 = 0;
+
+/// Decoded `STATUS_REG`.
+///
+/// The data available flags are cleared when the corresponding output registers are read. An overrun flag means new data overwrote a sample before it was read.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DataStatus {
+    pub x_ready: bool,
+    pub y_ready: bool,
+    pub z_ready: bool,
+    /// `ZYXDA`: new data is available on all axes.
+    pub zyx_ready: bool,
+    pub x_overrun: bool,
+    pub y_overrun: bool,
+    pub z_overrun: bool,
+    /// `ZYXOR`: new data overwrote the previous sample on all axes.
+    pub zyx_overrun: bool,
+}
+
+impl From<u8> for DataStatus {
+    fn from(value: u8) -> Self {
+        let is_set = |offset: u8| value & (1 << offset) != 0;
+        DataStatus {
+            x_ready: is_set(XDA_OFFSET),
+            y_ready: is_set(YDA_OFFSET),
+            z_ready: is_set(ZDA_OFFSET),
+            zyx_ready: is_set(ZYXDA_OFFSET),
+            x_overrun: is_set(XOR_OFFSET),
+            y_overrun: is_set(YOR_OFFSET),
+            z_overrun: is_set(ZOR_OFFSET),
+            zyx_overrun: is_set(ZYXOR_OFFSET),
+        }
+    }
+}
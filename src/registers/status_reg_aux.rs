@@ -0,0 +1,57 @@
+//! # STATUS_REG_AUX (07h)
+//! ## Fields:
+//! - `321or`: ADC channel 1, 2 and 3 data overrun.
+//! - `3or`: ADC channel 3 data overrun.
+//! - `2or`: ADC channel 2 data overrun.
+//! - `1or`: ADC channel 1 data overrun.
+//! - `321da`: ADC channel 1, 2 and 3 new data available.
+//! - `3da`: ADC channel 3 new data available.
+//! - `2da`: ADC channel 2 new data available.
+//! - `1da`: ADC channel 1 new data available.
+
+use crate::registers::ReadOnlyRegisterAddress;
+
+pub const ADDR: u8 = ReadOnlyRegisterAddress::StatusRegAux as u8;
+
+pub const OR_321_OFFSET: u8 = 7;
+pub const OR_3_OFFSET: u8 = 6;
+pub const OR_2_OFFSET: u8 = 5;
+pub const OR_1_OFFSET: u8 = 4;
+pub const DA_321_OFFSET: u8 = 3;
+pub const DA_3_OFFSET: u8 = 2;
+pub const DA_2_OFFSET: u8 = 1;
+pub const DA_1_OFFSET: u8 = 0;
+
+/// Decoded `STATUS_REG_AUX`.
+///
+/// Channel 3 carries the temperature sensor when it is enabled. The data available flags are cleared when the corresponding `OUT_ADC` registers are read.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AuxStatus {
+    pub adc1_ready: bool,
+    pub adc2_ready: bool,
+    pub adc3_ready: bool,
+    /// `321DA`: new data is available on all channels.
+    pub all_ready: bool,
+    pub adc1_overrun: bool,
+    pub adc2_overrun: bool,
+    pub adc3_overrun: bool,
+    /// `321OR`: new data overwrote the previous sample on all channels.
+    pub all_overrun: bool,
+}
+
+impl From<u8> for AuxStatus {
+    fn from(value: u8) -> Self {
+        let is_set = |offset: u8| value & (1 << offset) != 0;
+        AuxStatus {
+            adc1_ready: is_set(DA_1_OFFSET),
+            adc2_ready: is_set(DA_2_OFFSET),
+            adc3_ready: is_set(DA_3_OFFSET),
+            all_ready: is_set(DA_321_OFFSET),
+            adc1_overrun: is_set(OR_1_OFFSET),
+            adc2_overrun: is_set(OR_2_OFFSET),
+            adc3_overrun: is_set(OR_3_OFFSET),
+            all_overrun: is_set(OR_321_OFFSET),
+        }
+    }
+}
@@ -3,7 +3,7 @@
 //! - `adc_en`: ADC enable.
 //! - `temp_en`: Temperature sensor (T) enable.
 
-use crate::registers::{define_state_renderer, ReadWriteRegisterAddress};
+use crate::registers::{define_state_renderer, Entitled, ReadWriteRegisterAddress};
 
 pub const ADDR: u8 = ReadWriteRegisterAddress::TempCfgReg as u8;
 
@@ -28,6 +28,8 @@ pub mod adc_en {
         AdcEnabled = 0b1,
     }
 
+    crate::registers::define_field_reader!(TempCfgReg, AdcDisabled, AdcEnabled);
+
     pub struct AdcDisabled;
     pub struct AdcEnabled;
 
@@ -45,6 +47,9 @@ pub mod adc_en {
 ///   - `0b1`: T enabled.
 ///
 /// *Default value: 0 (T disabled).*
+///
+/// ### Entitlements:
+///    - [`temp_en::TempEnabled`] is entitled to [`adc_en::AdcEnabled`].
 pub mod temp_en {
     pub const ADDR: u8 = super::ADDR;
     pub const WIDTH: u8 = 1;
@@ -61,6 +66,8 @@ pub mod temp_en {
         TempEnabled = 0b1,
     }
 
+    crate::registers::define_field_reader!(TempCfgReg, TempDisabled, TempEnabled);
+
     pub struct TempDisabled;
     pub struct TempEnabled;
 
@@ -73,4 +80,8 @@ pub mod temp_en {
     }
 }
 
+// Entitlements of temp_en bit field
+impl<T: adc_en::State> Entitled<T> for temp_en::TempDisabled {}
+impl Entitled<adc_en::AdcEnabled> for temp_en::TempEnabled {}
+
 define_state_renderer!(adc_en, temp_en);
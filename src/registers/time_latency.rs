@@ -0,0 +1,10 @@
+//! # TIME_LATENCY (3Ch)
+//! ## Fields:
+//! - `tla`: Double click time latency, 1 LSb = 1/ODR.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::TimeLatency as u8;
+
+pub const TLA_OFFSET: u8 = 0;
+pub const TLA_WIDTH: u8 = 8;
@@ -0,0 +1,11 @@
+//! # TIME_LIMIT (3Bh)
+//! ## Fields:
+//! - `tli`: Click time limit, 1 LSb = 1/ODR.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::TimeLimit as u8;
+
+pub const TLI_OFFSET: u8 = 0;
+pub const TLI_WIDTH: u8 = 7;
+pub const TLI_MAX: u8 = (1 << TLI_WIDTH) - 1;
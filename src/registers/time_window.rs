@@ -0,0 +1,10 @@
+//! # TIME_WINDOW (3Dh)
+//! ## Fields:
+//! - `tw`: Double click time window, 1 LSb = 1/ODR.
+
+use crate::registers::ReadWriteRegisterAddress;
+
+pub const ADDR: u8 = ReadWriteRegisterAddress::TimeWindow as u8;
+
+pub const TW_OFFSET: u8 = 0;
+pub const TW_WIDTH: u8 = 8;
@@ -0,0 +1,41 @@
+//! # WHO_AM_I (0Fh)
+//! Device identification register, reading [`crate::LIS3DH_DEVICE_ID`] on a lis3dh.
+
+use core::fmt;
+
+use crate::registers::ReadOnlyRegisterAddress;
+use crate::LIS3DH_DEVICE_ID;
+
+pub const ADDR: u8 = ReadOnlyRegisterAddress::WhoAmI as u8;
+
+/// Device id read from `WHO_AM_I`, see [`crate::Lis3dh::read_device_id`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DeviceId(pub u8);
+
+impl DeviceId {
+    /// Returns true if the id is [`LIS3DH_DEVICE_ID`].
+    pub fn is_lis3dh(&self) -> bool {
+        self.0 == LIS3DH_DEVICE_ID
+    }
+}
+
+impl From<u8> for DeviceId {
+    fn from(value: u8) -> Self {
+        DeviceId(value)
+    }
+}
+
+/// Prints the id as hex, e.g. `0x33`.
+impl fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x}", self.0)
+    }
+}
+
+/// Prints the id as hex, matching the `Display` output.
+#[cfg(feature = "defmt")]
+impl defmt::Format for DeviceId {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=u8:#04x}", self.0)
+    }
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "libm")]
+
+mod common;
+
+use common::{block_on, default_config, MockBus, MockDelay};
+use lis3dh_driver::acceleration_data_structs::{Acceleration, AccelerationVector, TiltAngles};
+use lis3dh_driver::properties::{gravity_coefficient::GravityCoefficient, resolution::Resolution};
+use lis3dh_driver::registers::{
+    ctrl_reg1::lp_en::NormalPowerMode,
+    ctrl_reg4::{fs::S2G, hr::HighResolution},
+};
+use lis3dh_driver::{Error, Lis3dh};
+
+type G = GravityCoefficient<S2G, Resolution<NormalPowerMode, HighResolution>>;
+
+#[test]
+fn magnitude_of_unit_axes_is_sqrt_3() {
+    let one_g = Acceleration { value: 1000 };
+    let vector = AccelerationVector {
+        x: one_g,
+        y: one_g,
+        z: one_g,
+    };
+
+    assert!((vector.magnitude_g::<G>() - 1.732_050_8).abs() < 1e-4);
+}
+
+fn vector(x: i16, y: i16, z: i16) -> AccelerationVector {
+    AccelerationVector {
+        x: Acceleration { value: x },
+        y: Acceleration { value: y },
+        z: Acceleration { value: z },
+    }
+}
+
+fn assert_tilt_angles(vector: AccelerationVector, roll_deg: f32, pitch_deg: f32) {
+    let angles = vector.tilt_angles_deg::<G>();
+    assert!(
+        (angles.roll_deg - roll_deg).abs() < 1e-3 && (angles.pitch_deg - pitch_deg).abs() < 1e-3,
+        "{angles:?} vs roll {roll_deg}, pitch {pitch_deg}"
+    );
+}
+
+/// Flat is level; tilting +Y up rolls positive and tilting +X down pitches positive.
+#[test]
+fn tilt_angles_follow_axis_convention() {
+    assert_tilt_angles(vector(0, 0, 1000), 0.0, 0.0);
+    assert_tilt_angles(vector(-1000, 0, 0), 0.0, 90.0);
+    assert_tilt_angles(vector(0, 1000, 0), 90.0, 0.0);
+    assert_tilt_angles(vector(0, 707, 707), 45.0, 0.0);
+    assert_tilt_angles(vector(0, 0, -1000), 180.0, 0.0);
+}
+
+/// Reads the tilt angles with the OUT registers holding the left-justified `raw` samples of the default ±2 g, 10-bit config.
+fn get_tilt_angles(raw: [i16; 3]) -> TiltAngles {
+    let (bus, state) = MockBus::new();
+    let [x, y, z] = raw.map(i16::to_le_bytes);
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[x[0], x[1], y[0], y[1], z[0], z[1]]);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        let Ok(angles) = lis3dh.get_tilt_angles().await else {
+            panic!("get_tilt_angles failed");
+        };
+        angles
+    })
+}
+
+/// 1 g is 250 digits of 4 mg at ±2 g in normal mode, left-justified by 6 bits.
+const ONE_G: i16 = 250 << 6;
+
+#[test]
+fn get_tilt_angles_from_raw_samples() {
+    for (raw, roll_deg, pitch_deg) in [
+        ([0, 0, ONE_G], 0.0, 0.0),
+        ([-ONE_G, 0, 0], 0.0, 90.0),
+        ([ONE_G, 0, 0], 0.0, -90.0),
+        ([0, ONE_G, 0], 90.0, 0.0),
+        ([0, -ONE_G, 0], -90.0, 0.0),
+    ] {
+        let angles = get_tilt_angles(raw);
+        assert!(
+            (angles.roll_deg - roll_deg).abs() < 1e-3
+                && (angles.pitch_deg - pitch_deg).abs() < 1e-3,
+            "{raw:?}: {angles:?} vs roll {roll_deg}, pitch {pitch_deg}"
+        );
+    }
+}
+
+/// The noise floor is the per-axis sample standard deviation in g, and needs at least 2 samples.
+#[test]
+fn measure_noise_floor_returns_standard_deviation() {
+    let (bus, state) = MockBus::new();
+    let mut delay = MockDelay::default();
+    state.borrow_mut().registers[0x27] = 0b0000_1000; // ZYXDA
+                                                      // 10 and 20 digits of 4 mg, i.e. 40 and 80 mg steps on X and Z, with Y still.
+    state.borrow_mut().samples.extend([
+        [0, ONE_G, 0],
+        [10 << 6, ONE_G, 20 << 6],
+        [0, ONE_G, 0],
+        [10 << 6, ONE_G, 20 << 6],
+    ]);
+
+    let Ok(noise_floor) = block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        assert!(matches!(
+            lis3dh.measure_noise_floor(1, &mut delay).await,
+            Err(Error::InvalidArgument)
+        ));
+        lis3dh.measure_noise_floor(4, &mut delay).await
+    }) else {
+        panic!("measure_noise_floor failed");
+    };
+
+    for (measured, expected) in noise_floor.into_iter().zip([0.023_094, 0.0, 0.046_188]) {
+        assert!((measured - expected).abs() < 1e-5, "{noise_floor:?}");
+    }
+    assert!(state.borrow().samples.is_empty());
+}
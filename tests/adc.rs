@@ -0,0 +1,88 @@
+mod common;
+
+use common::{block_on, MockBus};
+use lis3dh_driver::config::Config;
+use lis3dh_driver::registers::out_adc::AdcChannel;
+use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg};
+use lis3dh_driver::Lis3dh;
+
+/// Reads ADC channel 2 in normal (10-bit) mode with OUT_ADC2 holding `raw`, returning the count and its voltage.
+fn read_adc(raw: i16) -> (i16, i32) {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x0A..0x0C].copy_from_slice(&raw.to_le_bytes());
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(
+            bus,
+            Config {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+                full_scale: ctrl_reg4::fs::S2G,
+                resolution_mode: ctrl_reg4::hr::NormalResolution,
+                block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+                endianness: ctrl_reg4::ble::LittleEndian,
+                adc_enable: temp_cfg_reg::adc_en::AdcEnabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+                fifo_mode: fifo_ctrl_reg::fm::Bypass,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+            },
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+        let Ok(counts) = lis3dh.read_adc(AdcChannel::Two).await else {
+            panic!("read failed");
+        };
+        (counts, lis3dh.adc_counts_to_millivolts(counts))
+    })
+}
+
+#[test]
+fn adc_count_is_right_justified_10_bit() {
+    assert_eq!(read_adc(0x0000), (0, 1200));
+    assert_eq!(read_adc(0x7FC0), (511, 1599));
+    assert_eq!(read_adc(-0x8000), (-512, 800));
+}
+
+/// `read_accel_and_adc1` reads ADC1 and the acceleration in two bursts, as the registers aren't contiguous.
+#[test]
+fn read_accel_and_adc1_reads_two_bursts() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x08..0x0A].copy_from_slice(&(-0x8000i16).to_le_bytes());
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[0x40, 0x00, 0xC0, 0xFF, 0xC0, 0x7F]);
+
+    let Ok((accel, adc1)) = block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(
+            bus,
+            Config {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+                full_scale: ctrl_reg4::fs::S2G,
+                resolution_mode: ctrl_reg4::hr::NormalResolution,
+                block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+                endianness: ctrl_reg4::ble::LittleEndian,
+                adc_enable: temp_cfg_reg::adc_en::AdcEnabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+                fifo_mode: fifo_ctrl_reg::fm::Bypass,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+            },
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+        state.borrow_mut().reads.clear();
+        lis3dh.read_accel_and_adc1().await
+    }) else {
+        panic!("read failed");
+    };
+
+    assert_eq!(adc1, -512);
+    assert_eq!([accel.x.value, accel.y.value, accel.z.value], [1, -1, 511]);
+    assert_eq!(state.borrow().reads, [(0x08, 2), (0x28, 6)]);
+}
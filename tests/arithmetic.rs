@@ -0,0 +1,134 @@
+use lis3dh_driver::acceleration_data_structs::{Acceleration, AccelerationVector};
+use lis3dh_driver::properties::{gravity_coefficient::GravityCoefficient, resolution::Resolution};
+use lis3dh_driver::registers::{
+    ctrl_reg1::lp_en::NormalPowerMode,
+    ctrl_reg4::{fs::S2G, hr::NormalResolution},
+};
+
+type G = GravityCoefficient<S2G, Resolution<NormalPowerMode, NormalResolution>>;
+
+fn vector(x: i16, y: i16, z: i16) -> AccelerationVector {
+    AccelerationVector {
+        x: Acceleration { value: x },
+        y: Acceleration { value: y },
+        z: Acceleration { value: z },
+    }
+}
+
+fn values(vector: AccelerationVector) -> [i16; 3] {
+    [vector.x.value, vector.y.value, vector.z.value]
+}
+
+#[test]
+fn add_and_sub_are_component_wise() {
+    let a = vector(100, -200, 300);
+    let b = vector(1, 2, 3);
+
+    assert_eq!(values(a + b), [101, -198, 303]);
+    assert_eq!(values(a - b), [99, -202, 297]);
+    assert_eq!(values(a - a), values(AccelerationVector::zero()));
+}
+
+#[test]
+fn add_and_sub_saturate() {
+    let max = vector(i16::MAX, i16::MIN, i16::MAX);
+    let one = vector(1, 1, -1);
+
+    assert_eq!(values(max + one), [i16::MAX, i16::MIN + 1, i16::MAX - 1]);
+    assert_eq!(values(max - one), [i16::MAX - 1, i16::MIN, i16::MAX]);
+}
+
+#[test]
+fn mul_and_div_are_component_wise() {
+    let a = vector(10, -20, 7);
+
+    assert_eq!(values(a * 3), [30, -60, 21]);
+    assert_eq!(values(a / 2), [5, -10, 3]);
+}
+
+#[test]
+fn mul_and_div_saturate() {
+    let extremes = vector(i16::MAX, i16::MIN, 2);
+
+    assert_eq!(values(extremes * 2), [i16::MAX, i16::MIN, 4]);
+    assert_eq!(values(extremes * -1), [-i16::MAX, i16::MAX, -2]);
+    assert_eq!(values(extremes * i32::MAX), [i16::MAX, i16::MIN, i16::MAX]);
+    assert_eq!(values(extremes / -1), [-i16::MAX, i16::MAX, -2]);
+}
+
+#[test]
+fn debug_prints_axes_on_one_line() {
+    assert_eq!(
+        format!("{:?}", vector(12, -4, 1000)),
+        "AccelerationVector { x: 12, y: -4, z: 1000 }"
+    );
+}
+
+#[test]
+fn display_g_prints_axes_in_g() {
+    let a = vector(3, -1, 250);
+
+    assert_eq!(
+        format!("{}", a.display_g::<G>()),
+        "(0.012, -0.004, 1.000) g"
+    );
+    assert_eq!(format!("{:.1}", a.display_g::<G>()), "(0.0, -0.0, 1.0) g");
+}
+
+#[test]
+fn axes_index_and_iterate_in_xyz_order() {
+    let a = vector(1, -2, 3);
+
+    assert_eq!(a.axes().map(|axis| axis.value), [1, -2, 3]);
+    assert_eq!([a[0].value, a[1].value, a[2].value], [1, -2, 3]);
+    assert_eq!(a.into_iter().map(|axis| axis.value as i32).sum::<i32>(), 2);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_range_panics() {
+    let _ = vector(1, 2, 3)[3];
+}
+
+#[test]
+fn rotate_x_90_maps_known_vector() {
+    assert_eq!(values(vector(1, 2, 3).rotate_x_90()), [1, -3, 2]);
+}
+
+#[test]
+fn rotate_x_neg_90_maps_known_vector() {
+    assert_eq!(values(vector(1, 2, 3).rotate_x_neg_90()), [1, 3, -2]);
+}
+
+#[test]
+fn rotate_y_90_maps_known_vector() {
+    assert_eq!(values(vector(1, 2, 3).rotate_y_90()), [3, 2, -1]);
+}
+
+#[test]
+fn rotate_y_neg_90_maps_known_vector() {
+    assert_eq!(values(vector(1, 2, 3).rotate_y_neg_90()), [-3, 2, 1]);
+}
+
+#[test]
+fn rotate_z_90_maps_known_vector() {
+    assert_eq!(values(vector(1, 2, 3).rotate_z_90()), [-2, 1, 3]);
+}
+
+#[test]
+fn rotate_z_neg_90_maps_known_vector() {
+    assert_eq!(values(vector(1, 2, 3).rotate_z_neg_90()), [2, -1, 3]);
+}
+
+/// A rotation followed by its negative gives back the input, in either order.
+#[test]
+fn rotations_are_undone_by_their_negative() {
+    let input = vector(1, 2, 3);
+
+    assert_eq!(values(input.rotate_x_90().rotate_x_neg_90()), values(input));
+    assert_eq!(values(input.rotate_x_neg_90().rotate_x_90()), values(input));
+    assert_eq!(values(input.rotate_y_90().rotate_y_neg_90()), values(input));
+    assert_eq!(values(input.rotate_y_neg_90().rotate_y_90()), values(input));
+    assert_eq!(values(input.rotate_z_90().rotate_z_neg_90()), values(input));
+    assert_eq!(values(input.rotate_z_neg_90().rotate_z_90()), values(input));
+}
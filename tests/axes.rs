@@ -0,0 +1,99 @@
+mod common;
+
+use common::{block_on, MockBus};
+use lis3dh_driver::config::Config;
+use lis3dh_driver::registers::{
+    ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg, Entitled,
+};
+use lis3dh_driver::Lis3dh;
+
+type AxesConfig<AxisEnable> = Config<
+    ctrl_reg1::odr::F100Hz,
+    ctrl_reg1::lp_en::NormalPowerMode,
+    AxisEnable,
+    ctrl_reg4::fs::S2G,
+    ctrl_reg4::hr::NormalResolution,
+    ctrl_reg4::bdu::ContinuousDataUpdate,
+    ctrl_reg4::ble::LittleEndian,
+    temp_cfg_reg::adc_en::AdcDisabled,
+    temp_cfg_reg::temp_en::TempDisabled,
+    ctrl_reg5::fifo_en::FifoDisabled,
+    fifo_ctrl_reg::fm::Bypass,
+    fifo_ctrl_reg::fth::Watermark<0>,
+>;
+
+/// The default config with the given axes enabled.
+fn axes_config<AxisEnable: ctrl_reg1::axis_enable::State + Entitled<ctrl_reg1::odr::F100Hz>>(
+    axis_enable: AxisEnable,
+) -> AxesConfig<AxisEnable> {
+    Config {
+        data_rate: ctrl_reg1::odr::F100Hz,
+        power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+        axis_enable,
+        full_scale: ctrl_reg4::fs::S2G,
+        resolution_mode: ctrl_reg4::hr::NormalResolution,
+        block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+        endianness: ctrl_reg4::ble::LittleEndian,
+        adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+        temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+        fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+        fifo_mode: fifo_ctrl_reg::fm::Bypass,
+        fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+    }
+}
+
+/// Reads the enabled axes of a mock holding X = 1, Y = 2 and Z = 3 (10-bit, left-justified), returning the values and the read transactions.
+fn read_enabled_axes<
+    AxisEnable: ctrl_reg1::axis_enable::State + Entitled<ctrl_reg1::odr::F100Hz>,
+>(
+    axis_enable: AxisEnable,
+) -> ([i16; 3], Vec<(u8, usize)>) {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[0x40, 0x00, 0x80, 0x00, 0xC0, 0x00]);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, axes_config(axis_enable)).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().reads.clear();
+
+        let Ok(vector) = lis3dh.read_enabled_axes().await else {
+            panic!("read_enabled_axes failed");
+        };
+        let reads = state.borrow().reads.clone();
+        ([vector.x.value, vector.y.value, vector.z.value], reads)
+    })
+}
+
+#[test]
+fn single_axis_reads_its_own_registers() {
+    assert_eq!(
+        read_enabled_axes(ctrl_reg1::axis_enable::XEnabled),
+        ([1, 0, 0], vec![(0x28, 2)])
+    );
+    assert_eq!(
+        read_enabled_axes(ctrl_reg1::axis_enable::ZEnabled),
+        ([0, 0, 3], vec![(0x2C, 2)])
+    );
+}
+
+#[test]
+fn contiguous_axes_read_in_one_burst() {
+    assert_eq!(
+        read_enabled_axes(ctrl_reg1::axis_enable::YZEnabled),
+        ([0, 2, 3], vec![(0x2A, 4)])
+    );
+    assert_eq!(
+        read_enabled_axes(ctrl_reg1::axis_enable::XYZEnabled),
+        ([1, 2, 3], vec![(0x28, 6)])
+    );
+}
+
+/// X and Z are read in one burst spanning Y, which is discarded.
+#[test]
+fn x_and_z_burst_across_y() {
+    assert_eq!(
+        read_enabled_axes(ctrl_reg1::axis_enable::XZEnabled),
+        ([1, 0, 3], vec![(0x28, 6)])
+    );
+}
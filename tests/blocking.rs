@@ -0,0 +1,26 @@
+mod common;
+
+use common::{block_on, default_config, MockBus};
+use lis3dh_driver::blocking::Lis3dhBlocking;
+use lis3dh_driver::Lis3dh;
+
+/// The blocking driver initialises the lis3dh with exactly the same reads and writes as the async driver.
+#[test]
+fn blocking_init_matches_async_init() {
+    let (bus, async_state) = MockBus::new();
+    assert!(block_on(Lis3dh::new(bus, default_config())).is_ok());
+
+    let (bus, blocking_state) = MockBus::new();
+    assert!(Lis3dhBlocking::new(bus, default_config()).is_ok());
+
+    let (async_state, blocking_state) = (async_state.borrow(), blocking_state.borrow());
+    assert_eq!(blocking_state.reads, async_state.reads);
+    assert_eq!(blocking_state.writes, async_state.writes);
+    assert_eq!(
+        blocking_state.writes,
+        [
+            (0x1E, vec![0x10, 0x00, 0x57, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            (0x2E, vec![0x00]),
+        ]
+    );
+}
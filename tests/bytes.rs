@@ -0,0 +1,49 @@
+use lis3dh_driver::acceleration_data_structs::{Acceleration, AccelerationVector};
+
+fn vector(x: i16, y: i16, z: i16) -> AccelerationVector {
+    AccelerationVector {
+        x: Acceleration { value: x },
+        y: Acceleration { value: y },
+        z: Acceleration { value: z },
+    }
+}
+
+fn values(vector: AccelerationVector) -> [i16; 3] {
+    [vector.x.value, vector.y.value, vector.z.value]
+}
+
+/// Every `i16` survives a round trip through both byte orders.
+#[test]
+fn acceleration_bytes_round_trip() {
+    for value in i16::MIN..=i16::MAX {
+        let a = Acceleration { value };
+        assert_eq!(Acceleration::from_be_bytes(a.to_be_bytes()).value, value);
+        assert_eq!(Acceleration::from_le_bytes(a.to_le_bytes()).value, value);
+    }
+}
+
+/// Vectors built from a spread of values, including both extremes, survive a round trip through both byte orders.
+#[test]
+fn acceleration_vector_bytes_round_trip() {
+    let samples = (i16::MIN..=i16::MAX).step_by(997).chain([i16::MAX, -1, 0]);
+    for value in samples {
+        let a = vector(value, value.wrapping_mul(7), value.wrapping_neg());
+        assert_eq!(
+            values(AccelerationVector::from_be_bytes(a.to_be_bytes())),
+            values(a)
+        );
+        assert_eq!(
+            values(AccelerationVector::from_le_bytes(a.to_le_bytes())),
+            values(a)
+        );
+    }
+}
+
+/// The byte layouts are upper-then-lower per axis for big endian and lower-then-upper for little endian.
+#[test]
+fn acceleration_vector_byte_layout() {
+    let a = vector(0x0102, 0x0304, 0x0506);
+
+    assert_eq!(a.to_be_bytes(), [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+    assert_eq!(a.to_le_bytes(), [0x02, 0x01, 0x04, 0x03, 0x06, 0x05]);
+}
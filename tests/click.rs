@@ -0,0 +1,135 @@
+mod common;
+
+use common::{block_on, default_config, MockBus};
+use lis3dh_driver::acceleration_data_structs::Axis;
+use lis3dh_driver::config::presets;
+use lis3dh_driver::registers::click_cfg::{ClickAxes, ClickConfig, DoubleTapTiming};
+use lis3dh_driver::registers::click_src::ClickSource;
+use lis3dh_driver::{Error, InterruptPin, Lis3dh};
+
+#[test]
+fn configure_click_writes_click_registers() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let cfg = ClickConfig {
+            single: ClickAxes {
+                x: true,
+                y: false,
+                z: true,
+            },
+            double: ClickAxes {
+                x: false,
+                y: true,
+                z: true,
+            },
+            threshold: 200,
+            latch_interrupt: true,
+            time_limit: 10,
+            time_latency: 20,
+            time_window: 30,
+        };
+        assert!(lis3dh.configure_click(cfg).await.is_ok());
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [(0x38, vec![0b0011_1001]), (0x3A, vec![0xFF, 10, 20, 30])]
+    );
+}
+
+#[test]
+fn click_source_decodes_single_negative_z_click() {
+    assert_eq!(
+        ClickSource::from(0b0101_1100),
+        ClickSource {
+            single: true,
+            double: false,
+            sign: true,
+            axis: Some(Axis::Z),
+        }
+    );
+}
+
+#[test]
+fn click_source_without_axis_has_no_axis() {
+    assert_eq!(ClickSource::from(0x00).axis, None);
+}
+
+const DOUBLE_TAP_TIMING: DoubleTapTiming = DoubleTapTiming {
+    time_limit_ms: 50,
+    time_latency_ms: 100,
+    time_window_ms: 300,
+};
+
+/// At 100 Hz and ±2 g, 500 mg is 31 counts of 16 mg and 50, 100 and 300 ms are 5, 10 and 30 periods; the click interrupt is then routed to the chosen pin, keeping its other sources.
+#[test]
+fn configure_double_tap_writes_click_registers_and_routing() {
+    for (pin, routing_address) in [(InterruptPin::Int1, 0x22), (InterruptPin::Int2, 0x25)] {
+        let (bus, state) = MockBus::new();
+
+        block_on(async {
+            let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+                panic!("new failed");
+            };
+            state.borrow_mut().registers[routing_address] = 0b0000_0010;
+            state.borrow_mut().writes.clear();
+
+            assert!(lis3dh
+                .configure_double_tap(500, DOUBLE_TAP_TIMING, pin)
+                .await
+                .is_ok());
+        });
+
+        assert_eq!(
+            state.borrow().writes,
+            [
+                (0x38, vec![0b0010_1010]),
+                (0x3A, vec![0x80 | 31, 5, 10, 30]),
+                (routing_address as u8, vec![0b1000_0010]),
+            ]
+        );
+    }
+}
+
+/// Times that round to 0 periods at a low output data rate, or overflow their register, are rejected without bus traffic.
+#[test]
+fn configure_double_tap_rejects_inexpressible_timing() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, presets::LOW_POWER_10HZ).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        // 20 ms is a fifth of a 10 Hz period.
+        let too_short = DoubleTapTiming {
+            time_limit_ms: 20,
+            ..DOUBLE_TAP_TIMING
+        };
+        assert!(matches!(
+            lis3dh
+                .configure_double_tap(500, too_short, InterruptPin::Int1)
+                .await,
+            Err(Error::InvalidArgument)
+        ));
+        // 30 s is 300 periods, more than `TIME_WINDOW` holds.
+        let too_long = DoubleTapTiming {
+            time_window_ms: 30_000,
+            ..DOUBLE_TAP_TIMING
+        };
+        assert!(matches!(
+            lis3dh
+                .configure_double_tap(500, too_long, InterruptPin::Int1)
+                .await,
+            Err(Error::InvalidArgument)
+        ));
+    });
+    assert!(state.borrow().writes.is_empty());
+}
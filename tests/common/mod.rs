@@ -0,0 +1,227 @@
+//! Shared helpers for the integration tests: a register-file mock of the lis3dh bus and a minimal executor.
+
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use embedded_hal_async::delay::DelayNs;
+use lis3dh_driver::bus::{BlockingLis3dhBus, Lis3dhBus};
+use lis3dh_driver::config::presets;
+use lis3dh_driver::registers::{ReadWriteRegisterAddress, RegisterAddress};
+
+/// Register contents and write log of a [`MockBus`], shared with the test so it can be inspected after the bus moves into a `Lis3dh`.
+pub struct MockState {
+    /// Register file indexed by byte address.
+    pub registers: [u8; 0x40],
+    /// Every write transaction as `(start address, bytes written)`.
+    pub writes: Vec<(u8, Vec<u8>)>,
+    /// Every read transaction as `(start address, number of bytes read)`.
+    pub reads: Vec<(u8, usize)>,
+    /// Number of upcoming transactions that fail with a bus error, like NACKs on a noisy line.
+    pub failures: u32,
+    /// Number of upcoming write transactions that succeed on the bus but leave the register file unchanged, like writes the lis3dh didn't latch.
+    pub dropped_writes: u32,
+    /// Raw `[x, y, z]` output change while a self-test is selected in `CTRL_REG4`, added to the enabled axes of `OUT_X_L` to `OUT_Z_H` reads.
+    pub self_test_offset: [i16; 3],
+    /// Raw `[x, y, z]` samples loaded into `OUT_X_L` to `OUT_Z_H` one per read of those registers, before the read, like new conversions arriving.
+    pub samples: VecDeque<[i16; 3]>,
+}
+
+impl Default for MockState {
+    fn default() -> Self {
+        MockState {
+            registers: [0; 0x40],
+            writes: Vec::new(),
+            reads: Vec::new(),
+            failures: 0,
+            dropped_writes: 0,
+            self_test_offset: [0; 3],
+            samples: VecDeque::new(),
+        }
+    }
+}
+
+/// A bus backed by an in-memory register file. Multiple reads/writes auto-increment the address like the lis3dh does.
+pub struct MockBus {
+    pub state: Rc<RefCell<MockState>>,
+}
+
+impl MockBus {
+    /// Returns the bus and a handle to its state.
+    pub fn new() -> (Self, Rc<RefCell<MockState>>) {
+        let state = Rc::new(RefCell::new(MockState::default()));
+        state.borrow_mut().registers[0x0F] = 0x33; // WHO_AM_I
+        (
+            MockBus {
+                state: state.clone(),
+            },
+            state,
+        )
+    }
+}
+
+impl MockState {
+    /// Consumes one of the injected failures, if any are left.
+    fn fail(&mut self) -> Result<(), ()> {
+        if self.failures == 0 {
+            return Ok(());
+        }
+        self.failures -= 1;
+        Err(())
+    }
+
+    /// Consumes one of the injected dropped writes, returning whether there was one.
+    fn drop_write(&mut self) -> bool {
+        if self.dropped_writes == 0 {
+            return false;
+        }
+        self.dropped_writes -= 1;
+        true
+    }
+}
+
+impl Lis3dhBus for MockBus {
+    type BusError = ();
+
+    async fn write(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        value: u8,
+    ) -> Result<(), Self::BusError> {
+        let address = register_address as u8;
+        let mut state = self.state.borrow_mut();
+        state.fail()?;
+        if !state.drop_write() {
+            state.registers[address as usize] = value;
+        }
+        state.writes.push((address, vec![value]));
+        Ok(())
+    }
+
+    async unsafe fn write_multiple(
+        &mut self,
+        start_address: ReadWriteRegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::BusError> {
+        let address = start_address as u8;
+        let mut state = self.state.borrow_mut();
+        state.fail()?;
+        let start = address as usize;
+        if !state.drop_write() {
+            state.registers[start..start + values.len()].copy_from_slice(values);
+        }
+        state.writes.push((address, values.to_vec()));
+        Ok(())
+    }
+
+    async fn read(
+        &mut self,
+        register_address: impl Into<RegisterAddress>,
+    ) -> Result<u8, Self::BusError> {
+        let address = register_address.into().byte_address();
+        let mut state = self.state.borrow_mut();
+        state.fail()?;
+        state.reads.push((address, 1));
+        Ok(state.registers[address as usize])
+    }
+
+    async fn read_multiple(
+        &mut self,
+        start_address: impl Into<RegisterAddress>,
+        result: &mut [u8],
+    ) -> Result<(), Self::BusError> {
+        let address = start_address.into().byte_address();
+        let mut state = self.state.borrow_mut();
+        state.fail()?;
+        state.reads.push((address, result.len()));
+        let start = address as usize;
+        if address == 0x28 && result.len() == 6 {
+            if let Some(sample) = state.samples.pop_front() {
+                for (axis, value) in sample.into_iter().enumerate() {
+                    state.registers[0x28 + 2 * axis..0x2A + 2 * axis]
+                        .copy_from_slice(&value.to_le_bytes());
+                }
+            }
+        }
+        result.copy_from_slice(&state.registers[start..start + result.len()]);
+        if address == 0x28 && result.len() == 6 && state.registers[0x23] & 0b110 != 0 {
+            for (axis, bytes) in result.chunks_exact_mut(2).enumerate() {
+                if state.registers[0x20] & 1 << axis != 0 {
+                    let value =
+                        i16::from_le_bytes([bytes[0], bytes[1]]) + state.self_test_offset[axis];
+                    bytes.copy_from_slice(&value.to_le_bytes());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The same register file driven through the blocking bus, so blocking and async drivers can be compared transaction for transaction.
+impl BlockingLis3dhBus for MockBus {
+    type BusError = ();
+
+    fn write(
+        &mut self,
+        register_address: ReadWriteRegisterAddress,
+        value: u8,
+    ) -> Result<(), Self::BusError> {
+        block_on(Lis3dhBus::write(self, register_address, value))
+    }
+
+    unsafe fn write_multiple(
+        &mut self,
+        start_address: ReadWriteRegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::BusError> {
+        // SAFETY: The caller upholds the same contract as for the async bus.
+        block_on(unsafe { Lis3dhBus::write_multiple(self, start_address, values) })
+    }
+
+    fn read(&mut self, register_address: impl Into<RegisterAddress>) -> Result<u8, Self::BusError> {
+        block_on(Lis3dhBus::read(self, register_address))
+    }
+
+    fn read_multiple(
+        &mut self,
+        start_address: impl Into<RegisterAddress>,
+        result: &mut [u8],
+    ) -> Result<(), Self::BusError> {
+        block_on(Lis3dhBus::read_multiple(self, start_address, result))
+    }
+}
+
+/// A delay that returns immediately, recording the total time it was asked to wait.
+#[derive(Default)]
+pub struct MockDelay {
+    pub total_ns: u64,
+}
+
+impl DelayNs for MockDelay {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += ns as u64;
+    }
+}
+
+/// Drives a future to completion. The mock bus never pends, so no real waker is needed.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// Power-on defaults except for a 100 Hz output data rate.
+pub type DefaultConfig = presets::Default100Hz2G;
+
+pub fn default_config() -> DefaultConfig {
+    presets::DEFAULT_100HZ_2G
+}
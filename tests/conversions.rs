@@ -0,0 +1,192 @@
+mod common;
+
+use common::{block_on, default_config, MockBus};
+use lis3dh_driver::acceleration_data_structs::Acceleration;
+use lis3dh_driver::properties::gravity_coefficient::{self, GravityCoefficient};
+use lis3dh_driver::properties::hp_cutoff_hz::{
+    self, hpcf_cutoff_hz, hpcf_for_cutoff_hz, HpCutoffHz,
+};
+use lis3dh_driver::properties::odr_hz::{self, OdrHz};
+use lis3dh_driver::properties::resolution::Resolution;
+use lis3dh_driver::properties::threshold_coefficient::{count_to_mg, mg_per_count, mg_to_count};
+use lis3dh_driver::registers::{
+    ctrl_reg1::lp_en::{LowPowerMode, NormalPowerMode},
+    ctrl_reg1::odr::{F100Hz, F1344Hz, F400Hz, F5376Hz, PowerDown},
+    ctrl_reg2::hpcf::{self, Hpcf0, Hpcf1, Hpcf3},
+    ctrl_reg4::{
+        fs::{S16G, S2G, S4G, S8G},
+        hr::{HighResolution, NormalResolution},
+    },
+};
+use lis3dh_driver::Lis3dh;
+
+/// `as_milli_g_i32` agrees with `as_g` to within float rounding.
+fn assert_milli_g_matches_float<G: gravity_coefficient::Property>(values: &[i16]) {
+    for &value in values {
+        let acceleration = Acceleration { value };
+        let float_milli_g = acceleration.as_g::<G>() * 1000.0;
+        let integer_milli_g = acceleration.as_milli_g_i32::<G>();
+        assert!(
+            (float_milli_g - integer_milli_g as f32).abs() <= float_milli_g.abs() * 1e-6 + 0.5,
+            "{value}: {float_milli_g} vs {integer_milli_g}"
+        );
+    }
+}
+
+#[test]
+fn milli_g_matches_float_path() {
+    assert_milli_g_matches_float::<
+        GravityCoefficient<S2G, Resolution<NormalPowerMode, HighResolution>>,
+    >(&[0, 1, -1, 1000, -2048, 2047]);
+    assert_milli_g_matches_float::<
+        GravityCoefficient<S16G, Resolution<LowPowerMode, NormalResolution>>,
+    >(&[0, 1, -1, 100, -128, 127]);
+}
+
+/// The bandwidth is half the output data rate, including the power-mode dependent 5.376 kHz rate.
+#[test]
+fn bandwidth_is_half_the_output_data_rate() {
+    assert_eq!(
+        <OdrHz<PowerDown, NormalPowerMode> as odr_hz::Property>::BANDWIDTH_HZ,
+        0.0
+    );
+    assert_eq!(
+        <OdrHz<F100Hz, NormalPowerMode> as odr_hz::Property>::BANDWIDTH_HZ,
+        50.0
+    );
+    assert_eq!(
+        <OdrHz<F5376Hz, LowPowerMode> as odr_hz::Property>::BANDWIDTH_HZ,
+        2688.0
+    );
+}
+
+/// `F1344Hz` and `F5376Hz` share a raw value, so the frequency is resolved with the power mode.
+#[test]
+fn frequency_disambiguates_shared_raw_value() {
+    assert_eq!(
+        <OdrHz<F1344Hz, NormalPowerMode> as odr_hz::Property>::FREQUENCY_HZ,
+        1344.0
+    );
+    assert_eq!(
+        <OdrHz<F5376Hz, LowPowerMode> as odr_hz::Property>::FREQUENCY_HZ,
+        5376.0
+    );
+}
+
+/// The high-pass cutoff scales with the output data rate and halves with each `hpcf` step.
+#[test]
+fn hp_cutoff_follows_odr_and_hpcf() {
+    type At100Hz = OdrHz<F100Hz, NormalPowerMode>;
+
+    assert_eq!(
+        <HpCutoffHz<At100Hz, Hpcf0> as hp_cutoff_hz::Property>::FREQUENCY_HZ,
+        2.0
+    );
+    assert_eq!(
+        <HpCutoffHz<At100Hz, Hpcf3> as hp_cutoff_hz::Property>::FREQUENCY_HZ,
+        0.25
+    );
+    assert_eq!(
+        <HpCutoffHz<OdrHz<F5376Hz, LowPowerMode>, Hpcf1> as hp_cutoff_hz::Property>::FREQUENCY_HZ,
+        53.76
+    );
+    assert_eq!(
+        <HpCutoffHz<OdrHz<PowerDown, NormalPowerMode>, Hpcf0> as hp_cutoff_hz::Property>::FREQUENCY_HZ,
+        0.0
+    );
+}
+
+/// The runtime helpers map each `hpcf` setting to its cutoff at an output data rate, and a target cutoff back to the nearest setting.
+#[test]
+fn hpcf_cutoff_helpers_follow_odr() {
+    type At400Hz = OdrHz<F400Hz, NormalPowerMode>;
+
+    assert_eq!(hpcf_cutoff_hz::<At400Hz>(&hpcf::Variant::Hpcf0), 8.0);
+    assert_eq!(hpcf_cutoff_hz::<At400Hz>(&hpcf::Variant::Hpcf2), 2.0);
+    assert_eq!(hpcf_for_cutoff_hz::<At400Hz>(100.0) as u8, 0b00);
+    assert_eq!(hpcf_for_cutoff_hz::<At400Hz>(3.5) as u8, 0b01);
+    assert_eq!(hpcf_for_cutoff_hz::<At400Hz>(1.2) as u8, 0b11);
+    assert_eq!(
+        hpcf_for_cutoff_hz::<OdrHz<F100Hz, NormalPowerMode>>(1.0) as u8,
+        0b01
+    );
+}
+
+/// The threshold mg/LSb follows the datasheet: 16, 32, 62 and 186 mg at ±2, ±4, ±8 and ±16 g.
+#[test]
+fn threshold_counts_follow_full_scale() {
+    assert_eq!(mg_per_count::<S2G>(), 16);
+    assert_eq!(mg_per_count::<S4G>(), 32);
+    assert_eq!(mg_per_count::<S8G>(), 62);
+    assert_eq!(mg_per_count::<S16G>(), 186);
+
+    assert_eq!(count_to_mg::<S2G>(22), 352);
+    assert_eq!(count_to_mg::<S16G>(127), 23_622);
+
+    assert_eq!(mg_to_count::<S2G>(350), 22);
+    assert_eq!(mg_to_count::<S8G>(30), 0);
+    assert_eq!(mg_to_count::<S8G>(31), 1);
+    assert_eq!(mg_to_count::<S4G>(u16::MAX), 127);
+    assert_eq!(mg_to_count::<S4G>(count_to_mg::<S4G>(100)), 100);
+}
+
+/// Every full-scale and resolution combination maps to the datasheet sensitivity in mg/digit.
+#[test]
+fn milli_g_per_digit_follows_full_scale_and_resolution() {
+    type Lp = Resolution<LowPowerMode, NormalResolution>;
+    type Normal = Resolution<NormalPowerMode, NormalResolution>;
+    type High = Resolution<NormalPowerMode, HighResolution>;
+
+    fn milli_g_per_digit<G: gravity_coefficient::Property>() -> u16 {
+        G::MILLI_G_PER_DIGIT
+    }
+
+    assert_eq!(milli_g_per_digit::<GravityCoefficient<S2G, Lp>>(), 16);
+    assert_eq!(milli_g_per_digit::<GravityCoefficient<S2G, Normal>>(), 4);
+    assert_eq!(milli_g_per_digit::<GravityCoefficient<S2G, High>>(), 1);
+    assert_eq!(milli_g_per_digit::<GravityCoefficient<S4G, Normal>>(), 8);
+    assert_eq!(milli_g_per_digit::<GravityCoefficient<S8G, High>>(), 4);
+    assert_eq!(milli_g_per_digit::<GravityCoefficient<S16G, Lp>>(), 192);
+    assert_eq!(milli_g_per_digit::<GravityCoefficient<S16G, Normal>>(), 48);
+    assert_eq!(milli_g_per_digit::<GravityCoefficient<S16G, High>>(), 12);
+}
+
+/// Reads `[Q8, Q16]` of each axis with the OUT registers holding the 10-bit `digits`, at ±2 g and after switching to ±16 g.
+fn read_qformat(digits: [i16; 3]) -> ([[i32; 3]; 2], [[i32; 3]; 2]) {
+    let (bus, state) = MockBus::new();
+    let [x, y, z] = digits.map(|digit| (digit << 6).to_le_bytes());
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[x[0], x[1], y[0], y[1], z[0], z[1]]);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        let (Ok(s2g_q8), Ok(s2g_q16)) = (
+            lis3dh.get_accel_qformat::<8>().await,
+            lis3dh.get_accel_qformat::<16>().await,
+        ) else {
+            panic!("get_accel_qformat failed");
+        };
+        let Ok(mut lis3dh) = lis3dh.set_full_scale(S16G).await else {
+            panic!("set_full_scale failed");
+        };
+        let (Ok(s16g_q8), Ok(s16g_q16)) = (
+            lis3dh.get_accel_qformat::<8>().await,
+            lis3dh.get_accel_qformat::<16>().await,
+        ) else {
+            panic!("get_accel_qformat failed");
+        };
+        ([s2g_q8, s2g_q16], [s16g_q8, s16g_q16])
+    })
+}
+
+/// At ±2 g a 10-bit digit is 4 mg and at ±16 g it is 48 mg; the fixed-point value is `mg * 2^FRAC / 1000` rounded toward zero.
+#[test]
+fn qformat_follows_full_scale_and_frac() {
+    let (s2g, s16g) = read_qformat([250, -125, 1]);
+
+    // 1000 mg, -500 mg and 4 mg.
+    assert_eq!(s2g, [[256, -128, 1], [65_536, -32_768, 262]]);
+    // 12000 mg, -6000 mg and 48 mg.
+    assert_eq!(s16g, [[3_072, -1_536, 12], [786_432, -393_216, 3_145]]);
+}
@@ -0,0 +1,205 @@
+mod common;
+
+use common::{block_on, default_config, MockBus};
+use lis3dh_driver::config::{ConfigError, DynamicConfig};
+use lis3dh_driver::{Error, Lis3dh};
+
+/// 100 Hz, ±2 g, 10-bit normal mode with all axes enabled, like the default preset.
+fn valid() -> DynamicConfig {
+    DynamicConfig {
+        data_rate: 0b0101,
+        power_mode: 0,
+        axis_enable: 0b111,
+        full_scale: 0,
+        resolution_mode: 0,
+        block_data_update: 0,
+        endianness: 0,
+        adc_enable: 0,
+        temperature_enable: 0,
+        fifo_enable: 0,
+        fifo_mode: 0,
+        fifo_watermark: 0,
+    }
+}
+
+fn validate_with(change: impl FnOnce(&mut DynamicConfig)) -> Result<(), ConfigError> {
+    let mut config = valid();
+    change(&mut config);
+    config.validate()
+}
+
+#[test]
+fn valid_config_passes() {
+    assert_eq!(valid().validate(), Ok(()));
+}
+
+/// Raw values outside of each field's variants are rejected and reported.
+#[test]
+fn out_of_range_raw_values_are_rejected() {
+    assert_eq!(
+        validate_with(|c| c.data_rate = 0b1010),
+        Err(ConfigError::InvalidDataRate(0b1010))
+    );
+    assert_eq!(
+        validate_with(|c| c.power_mode = 2),
+        Err(ConfigError::InvalidPowerMode(2))
+    );
+    assert_eq!(
+        validate_with(|c| c.axis_enable = 8),
+        Err(ConfigError::InvalidAxisEnable(8))
+    );
+    assert_eq!(
+        validate_with(|c| c.full_scale = 4),
+        Err(ConfigError::InvalidFullScale(4))
+    );
+    assert_eq!(
+        validate_with(|c| c.resolution_mode = 2),
+        Err(ConfigError::InvalidResolutionMode(2))
+    );
+    assert_eq!(
+        validate_with(|c| c.block_data_update = 2),
+        Err(ConfigError::InvalidBlockDataUpdate(2))
+    );
+    assert_eq!(
+        validate_with(|c| c.endianness = 2),
+        Err(ConfigError::InvalidEndianness(2))
+    );
+    assert_eq!(
+        validate_with(|c| c.adc_enable = 2),
+        Err(ConfigError::InvalidAdcEnable(2))
+    );
+    assert_eq!(
+        validate_with(|c| c.temperature_enable = 2),
+        Err(ConfigError::InvalidTemperatureEnable(2))
+    );
+    assert_eq!(
+        validate_with(|c| c.fifo_enable = 2),
+        Err(ConfigError::InvalidFifoEnable(2))
+    );
+    assert_eq!(
+        validate_with(|c| c.fifo_mode = 4),
+        Err(ConfigError::InvalidFifoMode(4))
+    );
+    assert_eq!(
+        validate_with(|c| c.fifo_watermark = 32),
+        Err(ConfigError::InvalidFifoWatermark(32))
+    );
+}
+
+/// The same combinations the type-state entitlements reject fail validation.
+#[test]
+fn entitlement_violations_are_rejected() {
+    // 1.6 kHz in normal power mode.
+    assert_eq!(
+        validate_with(|c| c.data_rate = 0b1000),
+        Err(ConfigError::DataRateNotEntitled)
+    );
+    // Every axis disabled at 100 Hz, which is fine once powered down.
+    assert_eq!(
+        validate_with(|c| c.axis_enable = 0),
+        Err(ConfigError::AxisEnableNotEntitled)
+    );
+    assert_eq!(
+        validate_with(|c| {
+            c.axis_enable = 0;
+            c.data_rate = 0;
+        }),
+        Ok(())
+    );
+    // High-resolution in low power mode.
+    assert_eq!(
+        validate_with(|c| {
+            c.power_mode = 1;
+            c.resolution_mode = 1;
+        }),
+        Err(ConfigError::ResolutionModeNotEntitled)
+    );
+    // Big endian without high-resolution.
+    assert_eq!(
+        validate_with(|c| c.endianness = 1),
+        Err(ConfigError::EndiannessNotEntitled)
+    );
+    // Temperature sensor without the ADC.
+    assert_eq!(
+        validate_with(|c| c.temperature_enable = 1),
+        Err(ConfigError::TemperatureEnableNotEntitled)
+    );
+    // Stream mode without enabling the FIFO.
+    assert_eq!(
+        validate_with(|c| c.fifo_mode = 0b10),
+        Err(ConfigError::FifoModeNotEntitled)
+    );
+}
+
+/// `apply` writes a valid config like `new` does, and nothing at all for an invalid one.
+#[test]
+fn apply_writes_only_valid_configs() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let mut invalid = valid();
+        invalid.temperature_enable = 1;
+        assert!(matches!(
+            invalid.apply(&mut lis3dh).await,
+            Err(Error::InvalidConfig(
+                ConfigError::TemperatureEnableNotEntitled
+            ))
+        ));
+        assert!(state.borrow().writes.is_empty());
+
+        assert!(valid().apply(&mut lis3dh).await.is_ok());
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [
+            (0x1E, vec![0x10, 0x00, 0x57, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            (0x2E, vec![0x00])
+        ]
+    );
+}
+
+/// `apply` rejects configs contradicting the driver's type-states, writing nothing, but accepts another active output data rate.
+#[test]
+fn apply_rejects_type_state_mismatches() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let changes: [fn(&mut DynamicConfig); 5] = [
+            |c| c.data_rate = 0b0000,
+            |c| c.power_mode = 1,
+            |c| c.resolution_mode = 1,
+            |c| c.full_scale = 0b11,
+            |c| {
+                c.resolution_mode = 1;
+                c.endianness = 1;
+            },
+        ];
+        for change in changes {
+            let mut mismatching = valid();
+            change(&mut mismatching);
+            assert_eq!(mismatching.validate(), Ok(()));
+            assert!(matches!(
+                mismatching.apply(&mut lis3dh).await,
+                Err(Error::InvalidConfig(ConfigError::TypeStateMismatch))
+            ));
+        }
+        assert!(state.borrow().writes.is_empty());
+
+        let mut faster = valid();
+        faster.data_rate = 0b0111;
+        assert!(faster.apply(&mut lis3dh).await.is_ok());
+    });
+
+    assert_eq!(state.borrow().writes[0].1[2], 0x77);
+}
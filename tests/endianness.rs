@@ -0,0 +1,64 @@
+mod common;
+
+use common::{block_on, MockBus};
+use lis3dh_driver::config::Config;
+use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg};
+use lis3dh_driver::Lis3dh;
+
+/// Raw 12-bit left-justified samples for each axis: 291, -1 and 2047.
+const SAMPLES: [i16; 3] = [0x1230, -0x10, 0x7FF0];
+
+/// Reads the acceleration vector with the OUT registers holding `out_bytes` under the given endianness.
+fn read_vector<Ble>(endianness: Ble, out_bytes: [u8; 6]) -> [i16; 3]
+where
+    Ble: ctrl_reg4::ble::State + lis3dh_driver::registers::Entitled<ctrl_reg4::hr::HighResolution>,
+{
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&out_bytes);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(
+            bus,
+            Config {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+                full_scale: ctrl_reg4::fs::S2G,
+                resolution_mode: ctrl_reg4::hr::HighResolution,
+                block_data_update: ctrl_reg4::bdu::BlockDataUpdate,
+                endianness,
+                adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+                fifo_mode: fifo_ctrl_reg::fm::Bypass,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+            },
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+        let Ok(vector) = lis3dh.get_accel_vector().await else {
+            panic!("read failed");
+        };
+        [vector.x.value, vector.y.value, vector.z.value]
+    })
+}
+
+#[test]
+fn little_and_big_endian_decode_to_the_same_values() {
+    let [x, y, z] = SAMPLES.map(i16::to_le_bytes);
+    let little_endian = read_vector(
+        ctrl_reg4::ble::LittleEndian,
+        [x[0], x[1], y[0], y[1], z[0], z[1]],
+    );
+
+    let [x, y, z] = SAMPLES.map(i16::to_be_bytes);
+    let big_endian = read_vector(
+        ctrl_reg4::ble::BigEndian,
+        [x[0], x[1], y[0], y[1], z[0], z[1]],
+    );
+
+    assert_eq!(little_endian, [291, -1, 2047]);
+    assert_eq!(big_endian, little_endian);
+}
@@ -0,0 +1,318 @@
+mod common;
+
+use common::{block_on, default_config, MockBus};
+use lis3dh_driver::config::Config;
+use lis3dh_driver::registers::fifo_ctrl_reg::FifoTrigger;
+use lis3dh_driver::registers::fifo_src_reg::FifoStatus;
+use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg};
+use lis3dh_driver::{Error, FifoRead, Lis3dh};
+
+type FifoConfig = Config<
+    ctrl_reg1::odr::F100Hz,
+    ctrl_reg1::lp_en::NormalPowerMode,
+    ctrl_reg1::axis_enable::XYZEnabled,
+    ctrl_reg4::fs::S2G,
+    ctrl_reg4::hr::NormalResolution,
+    ctrl_reg4::bdu::ContinuousDataUpdate,
+    ctrl_reg4::ble::LittleEndian,
+    temp_cfg_reg::adc_en::AdcDisabled,
+    temp_cfg_reg::temp_en::TempDisabled,
+    ctrl_reg5::fifo_en::FifoEnabled,
+    fifo_ctrl_reg::fm::Stream,
+    fifo_ctrl_reg::fth::Watermark<10>,
+>;
+
+/// Stream mode with a watermark of 10 samples.
+fn fifo_config() -> FifoConfig {
+    Config {
+        data_rate: ctrl_reg1::odr::F100Hz,
+        power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+        axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+        full_scale: ctrl_reg4::fs::S2G,
+        resolution_mode: ctrl_reg4::hr::NormalResolution,
+        block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+        endianness: ctrl_reg4::ble::LittleEndian,
+        adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+        temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+        fifo_enable: ctrl_reg5::fifo_en::FifoEnabled,
+        fifo_mode: fifo_ctrl_reg::fm::Stream,
+        fifo_watermark: fifo_ctrl_reg::fth::Watermark::<10>,
+    }
+}
+
+#[test]
+fn fifo_status_decodes_fifo_src_reg() {
+    assert_eq!(
+        FifoStatus::from(0b0010_0000),
+        FifoStatus {
+            watermark: false,
+            overrun: false,
+            empty: true,
+            sample_count: 0,
+        }
+    );
+    assert_eq!(
+        FifoStatus::from(0b1000_1010),
+        FifoStatus {
+            watermark: true,
+            overrun: false,
+            empty: false,
+            sample_count: 10,
+        }
+    );
+    assert_eq!(
+        FifoStatus::from(0b1101_1111),
+        FifoStatus {
+            watermark: true,
+            overrun: true,
+            empty: false,
+            sample_count: 31,
+        }
+    );
+}
+
+#[test]
+fn overrun_fifo_reports_full_depth_unread() {
+    assert_eq!(FifoStatus::from(0b0010_0000).unread_samples(), 0);
+    assert_eq!(FifoStatus::from(0b0000_0101).unread_samples(), 5);
+    assert_eq!(FifoStatus::from(0b1101_1111).unread_samples(), 32);
+}
+
+/// `read_fifo_array` fills the available samples and leaves the rest of the array zeroed.
+#[test]
+fn read_fifo_array_zeroes_missing_samples() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[0x40, 0x00, 0xC0, 0xFF, 0xC0, 0x7F]);
+    state.borrow_mut().registers[0x2F] = 3; // FSS
+
+    let Ok((samples, fifo_read)) = block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        lis3dh.read_fifo_array::<5>().await
+    }) else {
+        panic!("read_fifo_array failed");
+    };
+
+    assert_eq!(
+        fifo_read,
+        FifoRead {
+            sample_count: 3,
+            overrun: false
+        }
+    );
+    let values = samples.map(|sample| sample.axes().map(|axis| axis.value));
+    assert_eq!(
+        values,
+        [
+            [1, -1, 511],
+            [1, -1, 511],
+            [1, -1, 511],
+            [0, 0, 0],
+            [0, 0, 0]
+        ]
+    );
+}
+
+/// An overrun FIFO is still drained and flagged, and `reset_fifo` toggles through bypass back to the configured mode.
+#[test]
+fn overrun_is_flagged_and_reset_toggles_bypass() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, fifo_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().registers[0x2F] = 0b0101_1111; // OVRN_FIFO, FSS = 31
+
+        let Ok((_, fifo_read)) = lis3dh.read_fifo_array::<32>().await else {
+            panic!("read_fifo_array failed");
+        };
+        assert_eq!(
+            fifo_read,
+            FifoRead {
+                sample_count: 32,
+                overrun: true
+            }
+        );
+
+        state.borrow_mut().writes.clear();
+        assert!(lis3dh.reset_fifo().await.is_ok());
+        assert_eq!(
+            state.borrow().writes,
+            [(0x2E, vec![0b0000_1010]), (0x2E, vec![0b1000_1010])]
+        );
+    });
+}
+
+/// `configure_stream_to_fifo` writes `FM = 0b11`, the trigger and the watermark, rejecting watermarks above 31.
+#[test]
+fn configure_stream_to_fifo_writes_fifo_ctrl_reg() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, fifo_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh
+            .configure_stream_to_fifo(16, FifoTrigger::Int1)
+            .await
+            .is_ok());
+        assert!(lis3dh
+            .configure_stream_to_fifo(31, FifoTrigger::Int2)
+            .await
+            .is_ok());
+        assert!(matches!(
+            lis3dh.configure_stream_to_fifo(32, FifoTrigger::Int1).await,
+            Err(Error::InvalidArgument)
+        ));
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [(0x2E, vec![0b1101_0000]), (0x2E, vec![0b1111_1111])]
+    );
+}
+
+/// `fifo_iter` reads `FIFO_SRC_REG` before each sample and stops at `EMPTY`, picking up samples that arrive while iterating.
+#[test]
+fn fifo_iter_stops_when_empty() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[0x40, 0x00, 0xC0, 0xFF, 0xC0, 0x7F]);
+    state.borrow_mut().registers[0x2F] = 1; // FSS
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, fifo_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().reads.clear();
+
+        let mut samples = lis3dh.fifo_iter();
+        let Some(Ok(sample)) = samples.next().await else {
+            panic!("expected a sample");
+        };
+        assert_eq!(sample.axes().map(|axis| axis.value), [1, -1, 511]);
+
+        state.borrow_mut().registers[0x2F] = 1; // A new sample arrived.
+        assert!(matches!(samples.next().await, Some(Ok(_))));
+
+        state.borrow_mut().registers[0x2F] = 0b0010_0000; // EMPTY
+        assert!(samples.next().await.is_none());
+    });
+
+    assert_eq!(
+        state.borrow().reads,
+        [(0x2F, 1), (0x28, 6), (0x2F, 1), (0x28, 6), (0x2F, 1)]
+    );
+}
+
+/// `set_fifo_watermark` only replaces `FTH`, keeping the mode and trigger bits, rejects levels above 31 and reports a read-back mismatch.
+#[test]
+fn set_fifo_watermark_preserves_mode_and_trigger() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, fifo_config()).await else {
+            panic!("new failed");
+        };
+        assert!(lis3dh
+            .configure_stream_to_fifo(4, FifoTrigger::Int2)
+            .await
+            .is_ok());
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh.set_fifo_watermark(31).await.is_ok());
+        assert!(lis3dh.set_fifo_watermark(0).await.is_ok());
+        assert!(matches!(
+            lis3dh.set_fifo_watermark(32).await,
+            Err(Error::ThresholdOutOfRange)
+        ));
+
+        state.borrow_mut().dropped_writes = 1;
+        assert!(matches!(
+            lis3dh.set_fifo_watermark(7).await,
+            Err(Error::VerificationFailed {
+                expected: 7,
+                found: 0
+            })
+        ));
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [
+            (0x2E, vec![0b1111_1111]),
+            (0x2E, vec![0b1110_0000]),
+            (0x2E, vec![0b1110_0111])
+        ]
+    );
+}
+
+/// `is_fifo_full` and `is_fifo_empty` follow `OVRN_FIFO` and `EMPTY` of `FIFO_SRC_REG`.
+#[test]
+fn fifo_full_and_empty_follow_fifo_src_reg() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, fifo_config()).await else {
+            panic!("new failed");
+        };
+
+        for (fifo_src_reg, full, empty) in [
+            (0b0010_0000, false, true),
+            (0b0000_0101, false, false),
+            (0b1101_1111, true, false),
+        ] {
+            state.borrow_mut().registers[0x2F] = fifo_src_reg;
+            assert!(matches!(lis3dh.is_fifo_full().await, Ok(f) if f == full));
+            assert!(matches!(lis3dh.is_fifo_empty().await, Ok(e) if e == empty));
+        }
+    });
+}
+
+/// `set_fifo_trigger` only replaces `TR` of a stream-to-FIFO config, keeping the mode and watermark.
+#[test]
+fn set_fifo_trigger_writes_only_tr() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(
+            bus,
+            Config {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+                full_scale: ctrl_reg4::fs::S2G,
+                resolution_mode: ctrl_reg4::hr::NormalResolution,
+                block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+                endianness: ctrl_reg4::ble::LittleEndian,
+                adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoEnabled,
+                fifo_mode: fifo_ctrl_reg::fm::StreamToFifo,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<10>,
+            },
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh
+            .set_fifo_trigger(fifo_ctrl_reg::tr::Int2)
+            .await
+            .is_ok());
+        assert!(lis3dh
+            .set_fifo_trigger(fifo_ctrl_reg::tr::Int1)
+            .await
+            .is_ok());
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [(0x2E, vec![0b1110_1010]), (0x2E, vec![0b1100_1010])]
+    );
+}
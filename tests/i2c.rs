@@ -0,0 +1,105 @@
+mod common;
+
+use std::convert::Infallible;
+
+use common::block_on;
+use embedded_hal_async::i2c::{ErrorType, I2c, Operation, SevenBitAddress};
+use lis3dh_driver::bus::i2c::{I2cAddress, Lis3dhAsyncI2c};
+use lis3dh_driver::bus::Lis3dhBus;
+use lis3dh_driver::registers::{ReadOnlyRegisterAddress, ReadWriteRegisterAddress};
+
+/// One operation of a recorded I2C transaction.
+#[derive(Debug, PartialEq)]
+enum Recorded {
+    Write(Vec<u8>),
+    Read(usize),
+}
+
+/// An I2C controller that records every transaction as `(device address, operations)` and answers reads with `0xA5`.
+#[derive(Default)]
+struct MockI2c {
+    transactions: Vec<(u8, Vec<Recorded>)>,
+}
+
+impl ErrorType for MockI2c {
+    type Error = Infallible;
+}
+
+impl I2c<SevenBitAddress> for MockI2c {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Infallible> {
+        let recorded = operations
+            .iter_mut()
+            .map(|operation| match operation {
+                Operation::Write(bytes) => Recorded::Write(bytes.to_vec()),
+                Operation::Read(bytes) => {
+                    bytes.fill(0xA5);
+                    Recorded::Read(bytes.len())
+                }
+            })
+            .collect();
+        self.transactions.push((address, recorded));
+        Ok(())
+    }
+}
+
+/// Single-byte accesses send the plain sub-address, multi-byte accesses set its MSB to auto-increment.
+#[test]
+fn only_multi_byte_accesses_auto_increment() {
+    let mut bus = Lis3dhAsyncI2c::new(MockI2c::default(), I2cAddress::Sa0High);
+
+    block_on(async {
+        assert!(bus
+            .write(ReadWriteRegisterAddress::CtrlReg1, 0x57)
+            .await
+            .is_ok());
+        // SAFETY: CTRL_REG1 to CTRL_REG3 are all read/write registers.
+        let result = unsafe {
+            bus.write_multiple(ReadWriteRegisterAddress::CtrlReg1, &[0x57, 0x00, 0x40])
+                .await
+        };
+        assert!(result.is_ok());
+        assert!(matches!(
+            bus.read(ReadOnlyRegisterAddress::WhoAmI).await,
+            Ok(0xA5)
+        ));
+        let mut out = [0; 6];
+        assert!(bus
+            .read_multiple(ReadOnlyRegisterAddress::OutXL, &mut out)
+            .await
+            .is_ok());
+        assert_eq!(out, [0xA5; 6]);
+    });
+
+    assert_eq!(
+        bus.i2c.transactions,
+        [
+            (0x19, vec![Recorded::Write(vec![0x20, 0x57])]),
+            (
+                0x19,
+                vec![
+                    Recorded::Write(vec![0xA0]),
+                    Recorded::Write(vec![0x57, 0x00, 0x40])
+                ]
+            ),
+            (0x19, vec![Recorded::Write(vec![0x0F]), Recorded::Read(1)]),
+            (0x19, vec![Recorded::Write(vec![0xA8]), Recorded::Read(6)]),
+        ]
+    );
+}
+
+/// The sampled SA0 level selects the address: low is 0x18 and high is 0x19.
+#[test]
+fn sa0_level_selects_address() {
+    assert_eq!(I2cAddress::from(false) as u8, 0x18);
+    assert_eq!(I2cAddress::from(true) as u8, 0x19);
+
+    for (sa0_high, expected) in [(false, 0x18), (true, 0x19)] {
+        let mut bus = Lis3dhAsyncI2c::new(MockI2c::default(), sa0_high);
+        assert!(block_on(bus.read(ReadOnlyRegisterAddress::WhoAmI)).is_ok());
+        assert_eq!(bus.i2c.transactions[0].0, expected);
+    }
+}
@@ -0,0 +1,418 @@
+mod common;
+
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+use common::{block_on, default_config, MockBus, MockState};
+use embedded_hal::digital::ErrorType;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+use lis3dh_driver::properties::odr_hz::{Duration, OdrHz};
+use lis3dh_driver::properties::threshold_coefficient::Threshold;
+use lis3dh_driver::registers::ctrl_reg3::Int1Routing;
+use lis3dh_driver::registers::int1_src::{Face, InterruptSource};
+use lis3dh_driver::registers::int2_cfg::{Int2Config, InterruptEvents, InterruptMode};
+use lis3dh_driver::Lis3dh;
+
+/// An interrupt pin that is already at whichever level is awaited, recording which one was.
+#[derive(Default)]
+struct MockPin {
+    awaited_low: Option<bool>,
+}
+
+impl ErrorType for MockPin {
+    type Error = Infallible;
+}
+
+impl Wait for MockPin {
+    async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+        self.awaited_low = Some(false);
+        Ok(())
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+        self.awaited_low = Some(true);
+        Ok(())
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+        unimplemented!()
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+        unimplemented!()
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+        unimplemented!()
+    }
+}
+
+/// A delay that asserts a motion event in `INT1_SRC` once it has been waited on `polls` times.
+struct MotionAfter {
+    state: Rc<RefCell<MockState>>,
+    polls: u32,
+}
+
+impl DelayNs for MotionAfter {
+    async fn delay_ns(&mut self, _ns: u32) {
+        self.polls -= 1;
+        if self.polls == 0 {
+            self.state.borrow_mut().registers[0x31] = 0b0100_1000;
+        }
+    }
+}
+
+/// At ±2 g the threshold is 16 mg/LSb, so 350 mg rounds to 22 counts.
+#[test]
+fn configure_free_fall_writes_int1_registers() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh.configure_free_fall(350, 200).await.is_ok());
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [(0x32, vec![22, 127]), (0x30, vec![0b1001_0101])]
+    );
+}
+
+#[test]
+fn interrupt_source_decodes_low_events() {
+    assert_eq!(
+        InterruptSource::from(0b0101_0101),
+        InterruptSource {
+            active: true,
+            z_high: false,
+            z_low: true,
+            y_high: false,
+            y_low: true,
+            x_high: false,
+            x_low: true,
+        }
+    );
+}
+
+/// At 100 Hz, 500 ms is 50 samples, so `ACTD = (50 - 1) / 8` rounds to 6.
+#[test]
+fn configure_activity_writes_act_registers() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let duration = lis3dh.activity_duration_from_ms(500);
+        assert_eq!(duration, 6);
+        assert!(lis3dh.configure_activity(0xFF, duration).await.is_ok());
+    });
+
+    assert_eq!(state.borrow().writes, [(0x3E, vec![127, 6])]);
+}
+
+/// The activity interrupt sets only `I2_ACT` in `CTRL_REG6`, keeping the other routed sources.
+#[test]
+fn configure_activity_interrupt_routes_i2_act() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().registers[0x25] = 0b1000_0010;
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh.configure_activity_interrupt(20, 6).await.is_ok());
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [(0x3E, vec![20, 6]), (0x25, vec![0b1000_1010])]
+    );
+}
+
+#[test]
+fn configure_int1_routing_writes_ctrl_reg3() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let routing = Int1Routing::new().click().zyxda().overrun();
+        assert!(lis3dh.configure_int1_routing(routing).await.is_ok());
+    });
+
+    assert_eq!(state.borrow().writes, [(0x22, vec![0b1001_0010])]);
+}
+
+#[test]
+fn configure_6d_and_read_orientation() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh.configure_6d(800).await.is_ok());
+
+        state.borrow_mut().registers[0x31] = 0b0100_0000;
+        assert!(matches!(lis3dh.read_orientation().await, Ok(None)));
+        state.borrow_mut().registers[0x31] = 0b0110_0000;
+        assert!(matches!(
+            lis3dh.read_orientation().await,
+            Ok(Some(Face::ZUp))
+        ));
+        state.borrow_mut().registers[0x31] = 0b0100_0001;
+        assert!(matches!(
+            lis3dh.read_orientation().await,
+            Ok(Some(Face::XDown))
+        ));
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [(0x32, vec![50]), (0x30, vec![0xFF])]
+    );
+}
+
+/// `wait_for_interrupt` awaits the level selected by `INT_POLARITY`, then returns the decoded `INT1_SRC`.
+#[test]
+fn wait_for_interrupt_respects_polarity() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().registers[0x31] = 0b0100_0010;
+
+        let mut pin = MockPin::default();
+        let Ok(source) = lis3dh.wait_for_interrupt(&mut pin).await else {
+            panic!("wait_for_interrupt failed");
+        };
+        assert_eq!(pin.awaited_low, Some(false));
+        assert!(source.active && source.x_high);
+
+        state.borrow_mut().registers[0x25] = 0b0000_0010;
+        let mut pin = MockPin::default();
+        assert!(lis3dh.wait_for_interrupt(&mut pin).await.is_ok());
+        assert_eq!(pin.awaited_low, Some(true));
+    });
+}
+
+/// `set_int1_latched` toggles only `LIR_INT1` in `CTRL_REG5`, leaving the config intact.
+#[test]
+fn set_int1_latched_toggles_lir_int1() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().registers[0x24] = 0b0100_0000;
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh.set_int1_latched(true).await.is_ok());
+        assert!(lis3dh.set_int1_latched(false).await.is_ok());
+        assert_eq!(
+            state.borrow().writes,
+            [(0x24, vec![0b0100_1000]), (0x24, vec![0b0100_0000])]
+        );
+    });
+}
+
+/// Typed thresholds and durations convert with their own full scale and output data rate, and saturate at 127 counts.
+#[test]
+fn threshold_and_duration_carry_their_scaling() {
+    use lis3dh_driver::registers::ctrl_reg1::{lp_en::NormalPowerMode, odr::F400Hz};
+    use lis3dh_driver::registers::ctrl_reg4::fs::{S16G, S2G};
+
+    assert_eq!(Threshold::<S2G>::from_mg(250).raw(), 16);
+    assert_eq!(Threshold::<S16G>::from_mg(250).raw(), 1);
+    assert_eq!(Threshold::<S16G>::from_raw(2).mg(), 372);
+    assert_eq!(Threshold::<S2G>::from_raw(u8::MAX).raw(), 127);
+
+    type At400Hz = OdrHz<F400Hz, NormalPowerMode>;
+    assert_eq!(Duration::<At400Hz>::from_ms(75).raw(), 30);
+    assert_eq!(Duration::<At400Hz>::from_raw(30).ms(), 75.0);
+    assert_eq!(Duration::<At400Hz>::from_raw(u8::MAX).raw(), 127);
+}
+
+/// At ±2 g and 100 Hz, 250 mg rounds to 16 counts and 75 ms to 8 samples; out of range values saturate at 127.
+#[test]
+fn int1_threshold_and_duration_convert_units() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh
+            .set_int1_threshold(Threshold::from_mg(250))
+            .await
+            .is_ok());
+        assert!(lis3dh
+            .set_int1_duration(Duration::from_ms(75))
+            .await
+            .is_ok());
+        assert!(lis3dh
+            .set_int1_threshold(Threshold::from_mg(u16::MAX))
+            .await
+            .is_ok());
+        assert!(lis3dh
+            .set_int1_duration(Duration::from_ms(60_000))
+            .await
+            .is_ok());
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [
+            (0x32, vec![16]),
+            (0x33, vec![8]),
+            (0x32, vec![127]),
+            (0x33, vec![127])
+        ]
+    );
+}
+
+/// `read_int1_src` and `read_int2_src` decode their own source register.
+#[test]
+fn read_int_src_decodes_each_generator() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x31] = 0b0100_0001;
+    state.borrow_mut().registers[0x35] = 0b0110_0000;
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().reads.clear();
+
+        let Ok(int1) = lis3dh.read_int1_src().await else {
+            panic!("read_int1_src failed");
+        };
+        assert!(int1.active && int1.x_low && !int1.z_high);
+
+        let Ok(int2) = lis3dh.read_int2_src().await else {
+            panic!("read_int2_src failed");
+        };
+        assert!(int2.active && int2.z_high && !int2.x_low);
+    });
+
+    assert_eq!(state.borrow().reads, [(0x31, 1), (0x35, 1)]);
+}
+
+/// Wake-on-motion on interrupt 2: an OR of high events, with 250 mg rounding to 16 counts at ±2 g. Interrupt 1 is left untouched.
+#[test]
+fn configure_int2_writes_int2_registers() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let wake_on_motion = Int2Config {
+            mode: InterruptMode::OrCombination,
+            events: InterruptEvents {
+                x_high: true,
+                y_high: true,
+                z_high: true,
+                ..Default::default()
+            },
+            threshold_mg: 250,
+            duration_samples: 200,
+        };
+        assert!(lis3dh.configure_int2(wake_on_motion).await.is_ok());
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [(0x36, vec![16, 127]), (0x34, vec![0b0010_1010])]
+    );
+}
+
+/// `wait_for_motion` configures latched, high-pass filtered wake-on-motion routed to INT1, then polls `INT1_SRC` until it is active.
+#[test]
+fn wait_for_motion_polls_int1_src_until_active() {
+    let (bus, state) = MockBus::new();
+    let mut delay = MotionAfter {
+        state: state.clone(),
+        polls: 3,
+    };
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let Ok(source) = lis3dh.wait_for_motion(250, &mut delay).await else {
+            panic!("wait_for_motion failed");
+        };
+        assert!(source.active && source.y_high);
+    });
+
+    assert_eq!(delay.polls, 0);
+    assert_eq!(
+        state.borrow().writes,
+        [
+            (0x21, vec![0b0000_0001]),
+            (0x32, vec![16, 0]),
+            (0x30, vec![0b0010_1010]),
+            (0x24, vec![0b0000_1000]),
+            (0x22, vec![0b0100_0000]),
+        ]
+    );
+    assert_eq!(
+        state
+            .borrow()
+            .reads
+            .iter()
+            .filter(|(address, _)| *address == 0x31)
+            .count(),
+        4
+    );
+}
+
+/// `wait_for_motion_on_pin` configures the same wake-on-motion, then awaits the INT1 pin instead of polling.
+#[test]
+fn wait_for_motion_on_pin_awaits_int1() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().registers[0x31] = 0b0110_0000;
+        state.borrow_mut().writes.clear();
+
+        let mut pin = MockPin::default();
+        let Ok(source) = lis3dh.wait_for_motion_on_pin(250, &mut pin).await else {
+            panic!("wait_for_motion_on_pin failed");
+        };
+        assert_eq!(pin.awaited_low, Some(false));
+        assert!(source.active && source.z_high);
+    });
+
+    assert_eq!(state.borrow().writes.len(), 5);
+    assert_eq!(state.borrow().writes[4], (0x22, vec![0b0100_0000]));
+}
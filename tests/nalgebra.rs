@@ -0,0 +1,28 @@
+#![cfg(feature = "nalgebra")]
+
+use lis3dh_driver::acceleration_data_structs::{Acceleration, AccelerationVector};
+use lis3dh_driver::properties::gravity_coefficient::GravityCoefficient;
+use lis3dh_driver::properties::resolution::Resolution;
+use lis3dh_driver::registers::{
+    ctrl_reg1::lp_en::NormalPowerMode,
+    ctrl_reg4::{fs::S2G, hr::NormalResolution},
+};
+
+type G = GravityCoefficient<S2G, Resolution<NormalPowerMode, NormalResolution>>;
+
+/// `to_vector3` holds the same g values as `as_g`, axis by axis.
+#[test]
+fn to_vector3_matches_as_g() {
+    let vector = AccelerationVector {
+        x: Acceleration { value: 250 },
+        y: Acceleration { value: -125 },
+        z: Acceleration { value: 0 },
+    };
+
+    let vector3 = vector.to_vector3::<G>();
+
+    assert_eq!(vector3.x, vector.x.as_g::<G>());
+    assert_eq!(vector3.y, vector.y.as_g::<G>());
+    assert_eq!(vector3.z, 0.0);
+    assert_eq!(vector3.x, -2.0 * vector3.y);
+}
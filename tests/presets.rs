@@ -0,0 +1,122 @@
+mod common;
+
+use common::{block_on, MockBus, MockDelay};
+use lis3dh_driver::config::{presets, Config};
+use lis3dh_driver::properties::operating_mode::{OperatingMode, Property};
+use lis3dh_driver::properties::resolution;
+use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4};
+use lis3dh_driver::Lis3dh;
+
+/// `LOW_POWER_10HZ` writes 10 Hz low power mode with all axes enabled and resolves to 8-bit output.
+#[test]
+fn low_power_preset_renders_expected_bytes() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, presets::LOW_POWER_10HZ).await else {
+            panic!("new failed");
+        };
+
+        assert_eq!(
+            state.borrow().writes,
+            [
+                (0x1E, vec![0x10, 0x00, 0x2F, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                (0x2E, vec![0x00])
+            ]
+        );
+        assert_eq!(lis3dh.resolution_bits(), 8);
+    });
+}
+
+/// `config_bytes` matches what `new` writes, register by register.
+#[test]
+fn config_bytes_match_written_registers() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, presets::DEFAULT_100HZ_2G).await else {
+            panic!("new failed");
+        };
+
+        let config_bytes = lis3dh.config_bytes();
+        assert_eq!(
+            config_bytes,
+            [
+                (0x1E, 0x10),
+                (0x1F, 0x00),
+                (0x20, 0x57),
+                (0x21, 0x00),
+                (0x22, 0x00),
+                (0x23, 0x00),
+                (0x24, 0x00),
+                (0x25, 0x00),
+                (0x2E, 0x00)
+            ]
+        );
+        for (address, value) in config_bytes {
+            assert_eq!(state.borrow().registers[address as usize], value);
+        }
+    });
+}
+
+/// `new_with_delay` waits the 5 ms boot time, then the turn-on time of 1/ODR + 1 ms for normal and low-power mode.
+#[test]
+fn new_with_delay_waits_boot_and_turn_on_time() {
+    let (bus, _state) = MockBus::new();
+    let mut delay = MockDelay::default();
+    let result = block_on(Lis3dh::new_with_delay(
+        bus,
+        presets::DEFAULT_100HZ_2G,
+        &mut delay,
+    ));
+    assert!(result.is_ok());
+    assert_eq!(delay.total_ns, 16_000_000);
+
+    let (bus, _state) = MockBus::new();
+    let mut delay = MockDelay::default();
+    let result = block_on(Lis3dh::new_with_delay(
+        bus,
+        presets::LOW_POWER_10HZ,
+        &mut delay,
+    ));
+    assert!(result.is_ok());
+    assert_eq!(delay.total_ns, 106_000_000);
+}
+
+/// `from_operating_mode` matches the equivalent preset, and the operating mode resolves the shared ODR code by power mode.
+#[test]
+fn operating_mode_builds_config_and_resolves_properties() {
+    let config = Config::from_operating_mode(
+        OperatingMode {
+            data_rate: ctrl_reg1::odr::F100Hz,
+            power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+            resolution_mode: ctrl_reg4::hr::NormalResolution,
+        },
+        ctrl_reg1::axis_enable::XYZEnabled,
+        ctrl_reg4::fs::S2G,
+    );
+    let (bus, _state) = MockBus::new();
+    let Ok(lis3dh) = block_on(Lis3dh::new(bus, config)) else {
+        panic!("new failed");
+    };
+    let (bus, _state) = MockBus::new();
+    let Ok(preset) = block_on(Lis3dh::new(bus, presets::DEFAULT_100HZ_2G)) else {
+        panic!("new failed");
+    };
+    assert_eq!(lis3dh.config_bytes(), preset.config_bytes());
+
+    type LowPower5376Hz = OperatingMode<
+        ctrl_reg1::odr::F5376Hz,
+        ctrl_reg1::lp_en::LowPowerMode,
+        ctrl_reg4::hr::NormalResolution,
+    >;
+    type Normal1344Hz = OperatingMode<
+        ctrl_reg1::odr::F1344Hz,
+        ctrl_reg1::lp_en::NormalPowerMode,
+        ctrl_reg4::hr::HighResolution,
+    >;
+    assert_eq!(LowPower5376Hz::FREQUENCY_HZ, 5376.0);
+    assert!(LowPower5376Hz::RESOLUTION == resolution::Variant::R8Bit);
+    assert_eq!(Normal1344Hz::FREQUENCY_HZ, 1344.0);
+    assert!(Normal1344Hz::RESOLUTION == resolution::Variant::R12Bit);
+}
@@ -0,0 +1,287 @@
+mod common;
+
+use common::{block_on, MockBus};
+use lis3dh_driver::config::{Config, ValidLis3dhConfig};
+use lis3dh_driver::properties::gravity_coefficient;
+use lis3dh_driver::properties::operating_mode::OperatingMode;
+use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg};
+use lis3dh_driver::{Lis3dh, Lis3dhTypes};
+
+type InitialConfig = Config<
+    ctrl_reg1::odr::F100Hz,
+    ctrl_reg1::lp_en::NormalPowerMode,
+    ctrl_reg1::axis_enable::XYZEnabled,
+    ctrl_reg4::fs::S2G,
+    ctrl_reg4::hr::NormalResolution,
+    ctrl_reg4::bdu::ContinuousDataUpdate,
+    ctrl_reg4::ble::LittleEndian,
+    temp_cfg_reg::adc_en::AdcDisabled,
+    temp_cfg_reg::temp_en::TempDisabled,
+    ctrl_reg5::fifo_en::FifoDisabled,
+    fifo_ctrl_reg::fm::Bypass,
+    fifo_ctrl_reg::fth::Watermark<0>,
+>;
+
+type NewConfig = Config<
+    ctrl_reg1::odr::F400Hz,
+    ctrl_reg1::lp_en::LowPowerMode,
+    ctrl_reg1::axis_enable::XEnabled,
+    ctrl_reg4::fs::S16G,
+    ctrl_reg4::hr::NormalResolution,
+    ctrl_reg4::bdu::BlockDataUpdate,
+    ctrl_reg4::ble::LittleEndian,
+    temp_cfg_reg::adc_en::AdcDisabled,
+    temp_cfg_reg::temp_en::TempDisabled,
+    ctrl_reg5::fifo_en::FifoDisabled,
+    fifo_ctrl_reg::fm::Bypass,
+    fifo_ctrl_reg::fth::Watermark<0>,
+>;
+
+/// `reconfigure` moves the bus into the new driver and rewrites every configured register with the new config's bytes.
+#[test]
+fn reconfigure_rewrites_all_config_registers() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(lis3dh): Result<Lis3dh<MockBus, InitialConfig>, _> = Lis3dh::new(
+            bus,
+            Config {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+                full_scale: ctrl_reg4::fs::S2G,
+                resolution_mode: ctrl_reg4::hr::NormalResolution,
+                block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+                endianness: ctrl_reg4::ble::LittleEndian,
+                adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+                fifo_mode: fifo_ctrl_reg::fm::Bypass,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+            },
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+
+        assert_eq!(
+            state.borrow().writes,
+            [
+                (0x1E, vec![0x10, 0x00, 0x57, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                (0x2E, vec![0x00])
+            ]
+        );
+        state.borrow_mut().writes.clear();
+
+        let Ok(mut lis3dh): Result<Lis3dh<MockBus, NewConfig>, _> = lis3dh
+            .reconfigure(Config {
+                data_rate: ctrl_reg1::odr::F400Hz,
+                power_mode: ctrl_reg1::lp_en::LowPowerMode,
+                axis_enable: ctrl_reg1::axis_enable::XEnabled,
+                full_scale: ctrl_reg4::fs::S16G,
+                resolution_mode: ctrl_reg4::hr::NormalResolution,
+                block_data_update: ctrl_reg4::bdu::BlockDataUpdate,
+                endianness: ctrl_reg4::ble::LittleEndian,
+                adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+                fifo_mode: fifo_ctrl_reg::fm::Bypass,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+            })
+            .await
+        else {
+            panic!("reconfigure failed");
+        };
+
+        assert_eq!(
+            state.borrow().writes,
+            [
+                (0x1E, vec![0x10, 0x00, 0x79, 0x00, 0x00, 0xB0, 0x00, 0x00]),
+                (0x2E, vec![0x00])
+            ]
+        );
+
+        // The bus moved through `reconfigure` is still the one the driver talks to.
+        assert!(matches!(lis3dh.read_who_am_i().await, Ok(0x33)));
+    });
+}
+
+/// `set_data_rate` and `set_full_scale` each write only the register holding the changed field.
+#[test]
+fn partial_reconfiguration_writes_one_register() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, common::default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let Ok(lis3dh) = lis3dh.set_data_rate(ctrl_reg1::odr::F400Hz).await else {
+            panic!("set_data_rate failed");
+        };
+        assert_eq!(state.borrow().writes, [(0x20, vec![0x77])]);
+        state.borrow_mut().writes.clear();
+
+        assert_eq!(lis3dh.full_scale_g(), 2);
+        let Ok(lis3dh) = lis3dh.set_full_scale(ctrl_reg4::fs::S16G).await else {
+            panic!("set_full_scale failed");
+        };
+        assert_eq!(state.borrow().writes, [(0x23, vec![0x30])]);
+        assert_eq!(lis3dh.full_scale_g(), 16);
+        assert_eq!(lis3dh.resolution_bits(), 10);
+    });
+}
+
+/// `enter_low_power` clears `hr` before setting `lp_en`, and `enter_normal_power` only sets `lp_en` back.
+#[test]
+fn power_mode_switching_writes_only_power_fields() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(
+            bus,
+            Config {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+                full_scale: ctrl_reg4::fs::S4G,
+                resolution_mode: ctrl_reg4::hr::HighResolution,
+                block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+                endianness: ctrl_reg4::ble::LittleEndian,
+                adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+                fifo_mode: fifo_ctrl_reg::fm::Bypass,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+            },
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+        assert_eq!(lis3dh.resolution_bits(), 12);
+        state.borrow_mut().writes.clear();
+
+        let Ok(lis3dh) = lis3dh.enter_low_power().await else {
+            panic!("enter_low_power failed");
+        };
+        assert_eq!(
+            state.borrow().writes,
+            [(0x23, vec![0x10]), (0x20, vec![0x5F])]
+        );
+        assert_eq!(lis3dh.resolution_bits(), 8);
+        state.borrow_mut().writes.clear();
+
+        let Ok(lis3dh) = lis3dh.enter_normal_power().await else {
+            panic!("enter_normal_power failed");
+        };
+        assert_eq!(state.borrow().writes, [(0x20, vec![0x57])]);
+        assert_eq!(lis3dh.resolution_bits(), 10);
+    });
+}
+
+/// Gravity coefficient of a driver's config in mg/digit.
+fn milli_g_per_digit<L: Lis3dhTypes>(_: &L) -> u16 {
+    <<L::Config as ValidLis3dhConfig>::GravityCoefficient as gravity_coefficient::Property>::MILLI_G_PER_DIGIT
+}
+
+/// `set_operating_mode` writes `odr` and `lp_en` together and `hr` separately, `hr` first only when entering low power mode, and the returned driver's resolution and coefficient follow the mode.
+#[test]
+fn set_operating_mode_writes_lp_en_and_hr_in_order() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, common::default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let Ok(lis3dh) = lis3dh
+            .set_operating_mode(OperatingMode {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                resolution_mode: ctrl_reg4::hr::HighResolution,
+            })
+            .await
+        else {
+            panic!("set_operating_mode failed");
+        };
+        assert_eq!(
+            state.borrow().writes,
+            [(0x20, vec![0x57]), (0x23, vec![0x08])]
+        );
+        assert_eq!(
+            (lis3dh.resolution_bits(), milli_g_per_digit(&lis3dh)),
+            (12, 1)
+        );
+        state.borrow_mut().writes.clear();
+
+        let Ok(lis3dh) = lis3dh
+            .set_operating_mode(OperatingMode {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::LowPowerMode,
+                resolution_mode: ctrl_reg4::hr::NormalResolution,
+            })
+            .await
+        else {
+            panic!("set_operating_mode failed");
+        };
+        assert_eq!(
+            state.borrow().writes,
+            [(0x23, vec![0x00]), (0x20, vec![0x5F])]
+        );
+        assert_eq!(
+            (lis3dh.resolution_bits(), milli_g_per_digit(&lis3dh)),
+            (8, 16)
+        );
+        state.borrow_mut().writes.clear();
+
+        let Ok(lis3dh) = lis3dh
+            .set_operating_mode(OperatingMode {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                resolution_mode: ctrl_reg4::hr::NormalResolution,
+            })
+            .await
+        else {
+            panic!("set_operating_mode failed");
+        };
+        assert_eq!(
+            state.borrow().writes,
+            [(0x20, vec![0x57]), (0x23, vec![0x00])]
+        );
+        assert_eq!(
+            (lis3dh.resolution_bits(), milli_g_per_digit(&lis3dh)),
+            (10, 4)
+        );
+    });
+}
+
+/// `sleep` and `wake` only read and write `CTRL_REG1`, zeroing and restoring its ODR nibble.
+#[test]
+fn sleep_and_wake_touch_only_ctrl_reg1() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, common::default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+        state.borrow_mut().reads.clear();
+
+        let Ok(lis3dh) = lis3dh.sleep().await else {
+            panic!("sleep failed");
+        };
+        assert_eq!(state.borrow().writes, [(0x20, vec![0x07])]);
+        state.borrow_mut().writes.clear();
+
+        let Ok(mut lis3dh) = lis3dh.wake(ctrl_reg1::odr::F50Hz).await else {
+            panic!("wake failed");
+        };
+        assert_eq!(state.borrow().writes, [(0x20, vec![0x47])]);
+        assert_eq!(state.borrow().reads, [(0x20, 1), (0x20, 1)]);
+        assert!(lis3dh.get_accel_vector().await.is_ok());
+    });
+}
@@ -0,0 +1,290 @@
+mod common;
+
+use common::{block_on, default_config, MockBus, MockDelay};
+use lis3dh_driver::config::DEFAULT_CONFIG_BYTES;
+use lis3dh_driver::registers::{
+    ctrl_reg1, ctrl_reg4, ctrl_reg6, fifo_ctrl_reg, ReadOnlyRegisterAddress,
+    ReadWriteRegisterAddress,
+};
+use lis3dh_driver::{Error, Lis3dh};
+
+/// `modify_register` hands the current value to the closure and writes back its result.
+#[test]
+fn modify_register_writes_closure_result() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+        state.borrow_mut().registers[0x22] = 0b1010_0000;
+
+        // SAFETY: Setting I1_ZYXDA in CTRL_REG3 is a valid configuration.
+        let result = unsafe {
+            lis3dh
+                .modify_register(ReadWriteRegisterAddress::CtrlReg3, |value| {
+                    value | 0b0001_0000
+                })
+                .await
+        };
+        assert!(result.is_ok());
+    });
+
+    assert_eq!(state.borrow().writes, [(0x22, vec![0b1011_0000])]);
+}
+
+/// `reboot` sets `BOOT` on top of the current `CTRL_REG5`, waits the boot time, then restores the config.
+#[test]
+fn reboot_sets_boot_bit_and_rewrites_config() {
+    let (bus, state) = MockBus::new();
+    let mut delay = MockDelay::default();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+        state.borrow_mut().registers[0x24] = 0b0100_0000;
+
+        assert!(lis3dh.reboot(&mut delay).await.is_ok());
+    });
+
+    assert_eq!(delay.total_ns, 5_000_000);
+    assert_eq!(
+        state.borrow().writes,
+        [
+            (0x24, vec![0b1100_0000]),
+            (0x1E, vec![0x10, 0x00, 0x57, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            (0x2E, vec![0x00])
+        ]
+    );
+}
+
+/// `set_reference` writes `REFERENCE (0x26)` and `read_reference` reads it back.
+#[test]
+fn reference_round_trips() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        assert!(lis3dh.set_reference(0x5A).await.is_ok());
+        assert_eq!(state.borrow().writes, [(0x26, vec![0x5A])]);
+        assert!(matches!(lis3dh.read_reference().await, Ok(0x5A)));
+    });
+}
+
+/// `verify_config` passes straight after `new` and fails once a configured register is changed behind the driver's back.
+#[test]
+fn verify_config_detects_corrupted_register() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+
+        assert!(matches!(lis3dh.verify_config().await, Ok(true)));
+
+        // CTRL_REG3 isn't part of the config.
+        state.borrow_mut().registers[0x22] = 0x40;
+        assert!(matches!(lis3dh.verify_config().await, Ok(true)));
+
+        // Neither is the interrupt 1 latch in CTRL_REG5.
+        assert!(lis3dh.set_int1_latched(true).await.is_ok());
+        assert!(matches!(lis3dh.verify_config().await, Ok(true)));
+
+        state.borrow_mut().registers[0x24] = 0x40;
+        assert!(matches!(lis3dh.verify_config().await, Ok(false)));
+    });
+}
+
+/// `write_multiple_registers` accepts constant data without a mutable buffer.
+#[test]
+fn write_multiple_registers_takes_shared_slice() {
+    const CLICK_TIMING: [u8; 3] = [0x10, 0x20, 0x30];
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        // SAFETY: `TIME_LIMIT (0x3B)` to `TIME_WINDOW (0x3D)` are all writable.
+        let result = unsafe {
+            lis3dh
+                .write_multiple_registers(ReadWriteRegisterAddress::TimeLimit, &CLICK_TIMING)
+                .await
+        };
+        assert!(result.is_ok());
+        assert_eq!(state.borrow().writes, [(0x3B, CLICK_TIMING.to_vec())]);
+    });
+}
+
+/// `read_temp_cfg` decodes `ADC_EN` and `TEMP_EN` from `TEMP_CFG_REG (0x1F)`.
+#[test]
+fn read_temp_cfg_decodes_enable_bits() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        assert!(matches!(lis3dh.read_temp_cfg().await, Ok((false, false))));
+
+        state.borrow_mut().registers[0x1F] = 0b1000_0000;
+        assert!(matches!(lis3dh.read_temp_cfg().await, Ok((true, false))));
+
+        state.borrow_mut().registers[0x1F] = 0b1100_0000;
+        assert!(matches!(lis3dh.read_temp_cfg().await, Ok((true, true))));
+    });
+}
+
+/// `read_field` decodes the configured type-states back into their variants and rejects reserved raw values.
+#[test]
+fn read_field_decodes_variants() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+
+        assert!(matches!(
+            lis3dh.read_field::<ctrl_reg1::odr::Field>().await,
+            Ok(ctrl_reg1::odr::Variant::F100Hz)
+        ));
+        assert!(matches!(
+            lis3dh.read_field::<ctrl_reg4::fs::Field>().await,
+            Ok(ctrl_reg4::fs::Variant::S2G)
+        ));
+        assert!(matches!(
+            lis3dh.read_field::<fifo_ctrl_reg::fth::Field>().await,
+            Ok(0)
+        ));
+
+        state.borrow_mut().registers[0x25] = 0b0000_1010;
+        assert!(matches!(
+            lis3dh.read_field::<ctrl_reg6::i2_act::Field>().await,
+            Ok(ctrl_reg6::i2_act::Variant::ActEnabled)
+        ));
+        assert!(matches!(
+            lis3dh.read_field::<ctrl_reg6::int_polarity::Field>().await,
+            Ok(ctrl_reg6::int_polarity::Variant::ActiveLow)
+        ));
+
+        state.borrow_mut().registers[0x20] = 0b1111_0111; // Reserved ODR.
+        assert!(matches!(
+            lis3dh.read_field::<ctrl_reg1::odr::Field>().await,
+            Err(Error::InvalidFieldValue { raw: 0b1111 })
+        ));
+    });
+}
+
+/// `read_register_range` reads within a block of readable registers and rejects ranges crossing reserved addresses or the end of the map.
+#[test]
+fn read_register_range_checks_bounds() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x08..0x0E].copy_from_slice(&[1, 2, 3, 4, 5, 6]);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().reads.clear();
+
+        let mut adc = [0; 6];
+        assert!(lis3dh
+            .read_register_range(ReadOnlyRegisterAddress::OutAdc1L, &mut adc)
+            .await
+            .is_ok());
+        assert_eq!(adc, [1, 2, 3, 4, 5, 6]);
+
+        let mut click = [0; 9];
+        assert!(lis3dh
+            .read_register_range(ReadWriteRegisterAddress::ClickCfg, &mut click[..8])
+            .await
+            .is_ok());
+        assert!(matches!(
+            lis3dh
+                .read_register_range(ReadWriteRegisterAddress::ClickCfg, &mut click)
+                .await,
+            Err(Error::InvalidRange)
+        ));
+
+        let mut crossing = [0; 8];
+        assert!(matches!(
+            lis3dh
+                .read_register_range(ReadOnlyRegisterAddress::OutAdc1L, &mut crossing)
+                .await,
+            Err(Error::InvalidRange)
+        ));
+        assert!(matches!(
+            lis3dh
+                .read_register_range(ReadOnlyRegisterAddress::WhoAmI, &mut [0; 2])
+                .await,
+            Err(Error::InvalidRange)
+        ));
+        assert!(matches!(
+            lis3dh
+                .read_register_range(ReadOnlyRegisterAddress::WhoAmI, &mut [])
+                .await,
+            Err(Error::InvalidRange)
+        ));
+        assert_eq!(state.borrow().reads, [(0x08, 6), (0x38, 8)]);
+    });
+}
+
+/// Each byte of the reset-default control block matches the datasheet reset value of its register.
+#[test]
+fn default_config_bytes_match_datasheet_reset_values() {
+    let [ctrl_reg0, temp_cfg_reg, ctrl_reg1, ctrl_reg2, ctrl_reg3, ctrl_reg4, ctrl_reg5, ctrl_reg6] =
+        DEFAULT_CONFIG_BYTES;
+
+    assert_eq!(ctrl_reg0, 0x10);
+    assert_eq!(temp_cfg_reg, 0x00);
+    assert_eq!(ctrl_reg1, 0x07);
+    assert_eq!(ctrl_reg2, 0x00);
+    assert_eq!(ctrl_reg3, 0x00);
+    assert_eq!(ctrl_reg4, 0x00);
+    assert_eq!(ctrl_reg5, 0x00);
+    assert_eq!(ctrl_reg6, 0x00);
+}
+
+/// `reset_to_defaults` writes the power-on config, then clears the registers outside of it.
+#[test]
+fn reset_to_defaults_writes_reset_values() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+
+        let Ok(lis3dh) = lis3dh.reset_to_defaults().await else {
+            panic!("reset_to_defaults failed");
+        };
+        assert_eq!(lis3dh.config_bytes()[2], (0x20, 0x07));
+    });
+
+    assert_eq!(
+        state.borrow().writes,
+        [
+            (0x1E, vec![0x10, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            (0x2E, vec![0x00]),
+            (0x26, vec![0x00]),
+            (0x30, vec![0x00]),
+            (0x32, vec![0x00, 0x00]),
+            (0x34, vec![0x00]),
+            (0x36, vec![0x00, 0x00]),
+            (0x38, vec![0x00]),
+            (0x3A, vec![0x00; 6])
+        ]
+    );
+}
@@ -0,0 +1,22 @@
+mod common;
+
+use std::rc::Rc;
+
+use common::{block_on, default_config, MockBus};
+use lis3dh_driver::Lis3dh;
+
+/// `release` hands back the same bus that was moved into the driver.
+#[test]
+fn release_returns_owned_bus() {
+    let (bus, state) = MockBus::new();
+
+    let Ok(lis3dh) = block_on(Lis3dh::new(bus, default_config())) else {
+        panic!("new failed");
+    };
+    let bus = lis3dh.release();
+
+    assert!(Rc::ptr_eq(&bus.state, &state));
+
+    // The released bus can construct a new driver.
+    assert!(block_on(Lis3dh::new(bus, default_config())).is_ok());
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use common::{block_on, default_config, MockBus};
+use lis3dh_driver::config::Config;
+use lis3dh_driver::registers::{
+    ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg, Entitled,
+};
+use lis3dh_driver::Lis3dh;
+
+/// Reads the acceleration vector in little-endian with the OUT registers holding the left-justified `raw` samples.
+fn read_vector<LpEn, Hr>(power_mode: LpEn, resolution_mode: Hr, raw: [i16; 3]) -> [i16; 3]
+where
+    LpEn: ctrl_reg1::lp_en::State,
+    Hr: ctrl_reg4::hr::State + Entitled<LpEn>,
+{
+    let (bus, state) = MockBus::new();
+    let [x, y, z] = raw.map(i16::to_le_bytes);
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[x[0], x[1], y[0], y[1], z[0], z[1]]);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(
+            bus,
+            Config {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode,
+                axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+                full_scale: ctrl_reg4::fs::S2G,
+                resolution_mode,
+                block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+                endianness: ctrl_reg4::ble::LittleEndian,
+                adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+                fifo_mode: fifo_ctrl_reg::fm::Bypass,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+            },
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+        let Ok(vector) = lis3dh.get_accel_vector().await else {
+            panic!("read failed");
+        };
+        [vector.x.value, vector.y.value, vector.z.value]
+    })
+}
+
+fn read_8_bit(raw: [i16; 3]) -> [i16; 3] {
+    read_vector(
+        ctrl_reg1::lp_en::LowPowerMode,
+        ctrl_reg4::hr::NormalResolution,
+        raw,
+    )
+}
+
+fn read_10_bit(raw: [i16; 3]) -> [i16; 3] {
+    read_vector(
+        ctrl_reg1::lp_en::NormalPowerMode,
+        ctrl_reg4::hr::NormalResolution,
+        raw,
+    )
+}
+
+fn read_12_bit(raw: [i16; 3]) -> [i16; 3] {
+    read_vector(
+        ctrl_reg1::lp_en::NormalPowerMode,
+        ctrl_reg4::hr::HighResolution,
+        raw,
+    )
+}
+
+#[test]
+fn low_power_8_bit_sign_extends() {
+    assert_eq!(read_8_bit([-0x0100, 0x7F00, i16::MIN]), [-1, 127, -128]);
+    assert_eq!(read_8_bit([0x0100, 0x0000, -0x0200]), [1, 0, -2]);
+}
+
+#[test]
+fn normal_10_bit_sign_extends() {
+    assert_eq!(read_10_bit([-0x0040, 0x7FC0, i16::MIN]), [-1, 511, -512]);
+    assert_eq!(read_10_bit([0x0040, 0x0000, -0x0080]), [1, 0, -2]);
+}
+
+#[test]
+fn high_resolution_12_bit_sign_extends() {
+    assert_eq!(read_12_bit([-0x0010, 0x7FF0, i16::MIN]), [-1, 2047, -2048]);
+    assert_eq!(read_12_bit([0x0010, 0x0000, -0x0020]), [1, 0, -2]);
+}
+
+/// Bits below the resolution are ignored rather than rounded.
+#[test]
+fn unused_low_bits_are_discarded() {
+    assert_eq!(read_8_bit([i16::MAX, -1, 0x00FF]), [127, -1, 0]);
+    assert_eq!(read_10_bit([i16::MAX, -1, 0x003F]), [511, -1, 0]);
+    assert_eq!(read_12_bit([i16::MAX, -1, 0x000F]), [2047, -1, 0]);
+}
+
+/// `read_accel_raw_u16` keeps the left-justified register content, including the unused low bits.
+#[test]
+fn raw_read_is_unshifted() {
+    let raw = [-0x0040, 0x7FFF, 0x003F];
+    let (bus, state) = MockBus::new();
+    let [x, y, z] = raw.map(i16::to_le_bytes);
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[x[0], x[1], y[0], y[1], z[0], z[1]]);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        assert!(matches!(lis3dh.read_accel_raw_u16().await, Ok(read) if read == raw));
+    });
+    assert_eq!(read_10_bit(raw), [-1, 511, 0]);
+}
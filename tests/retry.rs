@@ -0,0 +1,88 @@
+mod common;
+
+use common::{block_on, default_config, MockBus, MockDelay};
+use lis3dh_driver::bus::retry::RetryBus;
+use lis3dh_driver::{Error, Lis3dh};
+
+/// Transient failures are retried with a doubling backoff and never reach the driver.
+#[test]
+fn retries_transient_failures() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().failures = 3;
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(
+            RetryBus::new(bus, 3, MockDelay::default()),
+            default_config(),
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+
+        // 500 µs, 1 ms and 2 ms before the third retry of the `WHO_AM_I` read succeeds.
+        let (_, delay) = lis3dh.release().release();
+        assert_eq!(delay.total_ns, 3_500_000);
+    });
+    assert_eq!(state.borrow().writes.len(), 2);
+}
+
+/// The error of the last attempt is returned once the retries are exhausted.
+#[test]
+fn propagates_error_after_exhausting_retries() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().failures = 3;
+
+    let result = block_on(Lis3dh::new(
+        RetryBus::new(bus, 2, MockDelay::default()),
+        default_config(),
+    ));
+
+    assert!(matches!(result, Err(Error::Bus(()))));
+    assert!(state.borrow().writes.is_empty());
+}
+
+/// Reads the acceleration vector with up to 3 retries after injecting `failures` bus errors, returning the result, the failures left unconsumed and the total backoff.
+fn read_with_retry(
+    failures: u32,
+) -> (
+    Result<lis3dh_driver::acceleration_data_structs::AccelerationVector, Error<()>>,
+    u32,
+    u64,
+) {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[0x40, 0x00, 0x80, 0x00, 0xC0, 0x00]);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().failures = failures;
+        let mut delay = MockDelay::default();
+        let result = lis3dh.read_accel_vector_with_retry(3, &mut delay).await;
+        (result, state.borrow().failures, delay.total_ns)
+    })
+}
+
+/// A read that fails `max_retries` times succeeds on the last attempt after one 500 µs backoff per failure.
+#[test]
+fn read_accel_vector_with_retry_succeeds_on_last_attempt() {
+    let (result, failures_left, delay_ns) = read_with_retry(3);
+
+    let Ok(vector) = result else {
+        panic!("read_accel_vector_with_retry failed");
+    };
+    assert_eq!([vector.x.value, vector.y.value, vector.z.value], [1, 2, 3]);
+    assert_eq!(failures_left, 0);
+    assert_eq!(delay_ns, 1_500_000);
+}
+
+/// A read that fails every attempt returns the bus error of the last one after `max_retries` backoffs, without attempting again.
+#[test]
+fn read_accel_vector_with_retry_returns_last_error() {
+    let (result, failures_left, delay_ns) = read_with_retry(5);
+
+    assert!(matches!(result, Err(Error::Bus(()))));
+    assert_eq!(failures_left, 1);
+    assert_eq!(delay_ns, 1_500_000);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::{block_on, default_config, MockBus, MockDelay};
+use lis3dh_driver::registers::ctrl_reg4;
+use lis3dh_driver::Lis3dh;
+
+/// Raw left-justified output change of 75 digits, i.e. 300 mg in normal mode at ±2 g.
+const IN_WINDOW: i16 = 75 << 6;
+
+/// The combined self-test reports each axis' verdict next to the overall one, and clears the self-test afterwards.
+#[test]
+fn self_test_reports_each_axis_verdict() {
+    let (bus, state) = MockBus::new();
+    let mut delay = MockDelay::default();
+    state.borrow_mut().registers[0x27] = 0b0000_1000; // ZYXDA
+    state.borrow_mut().self_test_offset = [IN_WINDOW, 0, -IN_WINDOW];
+
+    let Ok(result) = block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        lis3dh.run_self_test(&mut delay).await
+    }) else {
+        panic!("self-test failed");
+    };
+
+    assert_eq!(result.delta_mg, [300, 0, 300]);
+    assert_eq!(result.axis_passed, [true, false, true]);
+    assert!(!result.passed);
+    assert_eq!(state.borrow().registers[0x23], 0x00);
+}
+
+/// Each axis is tested with only itself enabled, and `CTRL_REG1` is restored afterwards.
+#[test]
+fn per_axis_self_test_isolates_axes_and_restores_ctrl_reg1() {
+    let (bus, state) = MockBus::new();
+    let mut delay = MockDelay::default();
+    state.borrow_mut().registers[0x27] = 0b0000_1000; // ZYXDA
+    state.borrow_mut().self_test_offset = [IN_WINDOW, 2 * IN_WINDOW, 0];
+
+    let Ok(results) = block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().writes.clear();
+        lis3dh.run_self_test_per_axis(&mut delay).await
+    }) else {
+        panic!("self-test failed");
+    };
+
+    assert_eq!(results.map(|axis| axis.delta_mg), [300, 600, 0]);
+    assert_eq!(results.map(|axis| axis.passed), [true, true, false]);
+    let ctrl_reg1_writes: Vec<u8> = state
+        .borrow()
+        .writes
+        .iter()
+        .filter(|(address, _)| *address == 0x20)
+        .map(|(_, bytes)| bytes[0])
+        .collect();
+    assert_eq!(ctrl_reg1_writes, [0x51, 0x52, 0x54, 0x57]);
+    // Three tests, each settling twice.
+    assert!(delay.total_ns >= 6 * 90_000_000);
+}
+
+/// The window is scaled with the full scale: at ±8 g (16 mg/digit) it spans 272 to 5760 mg.
+#[test]
+fn self_test_window_follows_full_scale() {
+    let (bus, state) = MockBus::new();
+    let mut delay = MockDelay::default();
+    state.borrow_mut().registers[0x27] = 0b0000_1000; // ZYXDA
+    state.borrow_mut().self_test_offset = [100 << 6, 10 << 6, 300 << 6];
+
+    let Ok(result) = block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        let Ok(mut lis3dh) = lis3dh.set_full_scale(ctrl_reg4::fs::S8G).await else {
+            panic!("set_full_scale failed");
+        };
+        lis3dh.run_self_test(&mut delay).await
+    }) else {
+        panic!("self-test failed");
+    };
+
+    assert_eq!(result.delta_mg, [1600, 160, 4800]);
+    assert_eq!(result.axis_passed, [true, false, true]);
+    assert!(!result.passed);
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "serde")]
+
+use lis3dh_driver::acceleration_data_structs::{Acceleration, AccelerationVector};
+
+/// A vector survives a round trip through postcard, as used for logging samples to flash.
+#[test]
+fn acceleration_vector_postcard_round_trip() {
+    let vector = AccelerationVector {
+        x: Acceleration { value: 12 },
+        y: Acceleration { value: -4 },
+        z: Acceleration { value: i16::MIN },
+    };
+
+    let mut buffer = [0; 16];
+    let Ok(bytes) = postcard::to_slice(&vector, &mut buffer) else {
+        panic!("serialize failed");
+    };
+    let Ok(decoded) = postcard::from_bytes::<AccelerationVector>(bytes) else {
+        panic!("deserialize failed");
+    };
+
+    assert_eq!(
+        [decoded.x.value, decoded.y.value, decoded.z.value],
+        [12, -4, i16::MIN]
+    );
+}
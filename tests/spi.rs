@@ -0,0 +1,292 @@
+mod common;
+
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+use common::{block_on, default_config};
+use embedded_hal::digital::{self, OutputPin};
+use embedded_hal::spi::ErrorKind;
+use embedded_hal_async::spi::{ErrorType, Operation, SpiBus, SpiDevice};
+use lis3dh_driver::bus::spi::{Lis3dhAsyncSpi3Wire, Lis3dhAsyncSpiBus, SpiBusError};
+use lis3dh_driver::bus::Lis3dhBus;
+use lis3dh_driver::registers::{ReadOnlyRegisterAddress, ReadWriteRegisterAddress};
+use lis3dh_driver::Lis3dh;
+
+/// A 3-wire lis3dh: the first byte of a transaction is the command and the rest is data. It only answers reads once `SIM` is set.
+struct Mock3WireSpi {
+    registers: [u8; 0x40],
+    used_read_operation: bool,
+}
+
+impl ErrorType for Mock3WireSpi {
+    type Error = Infallible;
+}
+
+impl SpiDevice for Mock3WireSpi {
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), Infallible> {
+        let mut command = None;
+        let mut address = 0;
+        for operation in operations {
+            let bytes: &mut [u8] = match operation {
+                Operation::Write(bytes) => &mut bytes.to_vec(),
+                Operation::TransferInPlace(bytes) => bytes,
+                Operation::Read(bytes) => {
+                    self.used_read_operation = true;
+                    bytes
+                }
+                _ => unimplemented!(),
+            };
+            for byte in bytes.iter_mut() {
+                let Some(command) = command else {
+                    command = Some(*byte);
+                    address = (*byte & 0x3F) as usize;
+                    continue;
+                };
+                if command & 0x80 != 0 {
+                    let sim_set = self.registers[0x23] & 0x01 != 0;
+                    *byte = if sim_set {
+                        self.registers[address]
+                    } else {
+                        0xFF
+                    };
+                } else {
+                    self.registers[address] = *byte;
+                }
+                if command & 0x40 != 0 {
+                    address += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// After enabling 3-wire mode, `new` reads `WHO_AM_I` over the shared line and the config write keeps `SIM` set.
+#[test]
+fn three_wire_keeps_sim_set_through_config_writes() {
+    let mut spi = Mock3WireSpi {
+        registers: [0; 0x40],
+        used_read_operation: false,
+    };
+    spi.registers[0x0F] = 0x33;
+    spi.registers[0x28..0x2E].copy_from_slice(&[0x40, 0x00, 0xC0, 0xFF, 0xC0, 0x7F]);
+    let mut bus = Lis3dhAsyncSpi3Wire { spi };
+
+    let bus = block_on(async {
+        assert!(bus.enable_3_wire_mode().await.is_ok());
+
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        let Ok(vector) = lis3dh.get_accel_vector().await else {
+            panic!("get_accel_vector failed");
+        };
+        assert_eq!(
+            [vector.x.value, vector.y.value, vector.z.value],
+            [1, -1, 511]
+        );
+        lis3dh.release()
+    });
+
+    assert_eq!(bus.spi.registers[0x23], 0x01);
+    assert_eq!(bus.spi.registers[0x20], 0x57);
+    assert!(!bus.spi.used_read_operation);
+}
+
+/// What happened on the wires of a [`Lis3dhAsyncSpiBus`], in order.
+#[derive(Debug, PartialEq)]
+enum Event {
+    Select,
+    Deselect,
+    Write(Vec<u8>),
+    Read(usize),
+    Flush,
+}
+
+type EventLog = Rc<RefCell<Vec<Event>>>;
+
+/// A raw SPI bus that logs its traffic and fails its next write if asked to.
+struct MockSpiBus {
+    events: EventLog,
+    fail_write: bool,
+}
+
+impl ErrorType for MockSpiBus {
+    type Error = ErrorKind;
+}
+
+impl SpiBus for MockSpiBus {
+    async fn read(&mut self, words: &mut [u8]) -> Result<(), ErrorKind> {
+        words.fill(0xA5);
+        self.events.borrow_mut().push(Event::Read(words.len()));
+        Ok(())
+    }
+
+    async fn write(&mut self, words: &[u8]) -> Result<(), ErrorKind> {
+        if self.fail_write {
+            return Err(ErrorKind::Overrun);
+        }
+        self.events.borrow_mut().push(Event::Write(words.to_vec()));
+        Ok(())
+    }
+
+    async fn transfer(&mut self, _read: &mut [u8], _write: &[u8]) -> Result<(), ErrorKind> {
+        unimplemented!()
+    }
+
+    async fn transfer_in_place(&mut self, _words: &mut [u8]) -> Result<(), ErrorKind> {
+        unimplemented!()
+    }
+
+    async fn flush(&mut self) -> Result<(), ErrorKind> {
+        self.events.borrow_mut().push(Event::Flush);
+        Ok(())
+    }
+}
+
+/// A chip select pin that logs its level changes and fails to drive low or high if asked to.
+struct MockCs {
+    events: EventLog,
+    fail_low: bool,
+    fail_high: bool,
+}
+
+impl digital::ErrorType for MockCs {
+    type Error = digital::ErrorKind;
+}
+
+impl OutputPin for MockCs {
+    fn set_low(&mut self) -> Result<(), digital::ErrorKind> {
+        if self.fail_low {
+            return Err(digital::ErrorKind::Other);
+        }
+        self.events.borrow_mut().push(Event::Select);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), digital::ErrorKind> {
+        if self.fail_high {
+            return Err(digital::ErrorKind::Other);
+        }
+        self.events.borrow_mut().push(Event::Deselect);
+        Ok(())
+    }
+}
+
+fn spi_bus() -> (Lis3dhAsyncSpiBus<MockSpiBus, MockCs>, EventLog) {
+    let events = EventLog::default();
+    (
+        Lis3dhAsyncSpiBus {
+            spi: MockSpiBus {
+                events: events.clone(),
+                fail_write: false,
+            },
+            cs: MockCs {
+                events: events.clone(),
+                fail_low: false,
+                fail_high: false,
+            },
+        },
+        events,
+    )
+}
+
+/// Chip select is asserted before and released after each transaction, once the bus is flushed.
+#[test]
+fn spi_bus_asserts_chip_select_around_each_transaction() {
+    let (mut bus, events) = spi_bus();
+
+    block_on(async {
+        assert!(bus
+            .write(ReadWriteRegisterAddress::CtrlReg1, 0x57)
+            .await
+            .is_ok());
+        // SAFETY: CTRL_REG1 and CTRL_REG2 are both read/write registers.
+        let result = unsafe {
+            bus.write_multiple(ReadWriteRegisterAddress::CtrlReg1, &[0x57, 0x00])
+                .await
+        };
+        assert!(result.is_ok());
+        assert!(matches!(
+            bus.read(ReadOnlyRegisterAddress::WhoAmI).await,
+            Ok(0xA5)
+        ));
+        let mut out = [0; 6];
+        assert!(bus
+            .read_multiple(ReadOnlyRegisterAddress::OutXL, &mut out)
+            .await
+            .is_ok());
+    });
+
+    use Event::*;
+    assert_eq!(
+        *events.borrow(),
+        [
+            Select,
+            Write(vec![0x20, 0x57]),
+            Flush,
+            Deselect,
+            Select,
+            Write(vec![0x60]),
+            Write(vec![0x57, 0x00]),
+            Flush,
+            Deselect,
+            Select,
+            Write(vec![0x8F]),
+            Read(1),
+            Flush,
+            Deselect,
+            Select,
+            Write(vec![0xE8]),
+            Read(6),
+            Flush,
+            Deselect,
+        ]
+    );
+}
+
+/// A failed transfer still releases chip select and surfaces the SPI error.
+#[test]
+fn spi_bus_releases_chip_select_on_spi_error() {
+    let (mut bus, events) = spi_bus();
+    bus.spi.fail_write = true;
+
+    let result = block_on(bus.write(ReadWriteRegisterAddress::CtrlReg1, 0x57));
+
+    assert!(matches!(result, Err(SpiBusError::Spi(ErrorKind::Overrun))));
+    assert_eq!(*events.borrow(), [Event::Select, Event::Deselect]);
+}
+
+/// Failing to drive chip select is surfaced as a chip select error, and no transfer happens if it can't be asserted.
+#[test]
+fn spi_bus_surfaces_chip_select_errors() {
+    let (mut bus, events) = spi_bus();
+    bus.cs.fail_low = true;
+    let result = block_on(bus.read(ReadOnlyRegisterAddress::WhoAmI));
+    assert!(matches!(
+        result,
+        Err(SpiBusError::ChipSelect(digital::ErrorKind::Other))
+    ));
+    assert!(events.borrow().is_empty());
+
+    let (mut bus, events) = spi_bus();
+    bus.cs.fail_high = true;
+    let result = block_on(bus.read(ReadOnlyRegisterAddress::WhoAmI));
+    assert!(matches!(
+        result,
+        Err(SpiBusError::ChipSelect(digital::ErrorKind::Other))
+    ));
+    assert_eq!(
+        *events.borrow(),
+        [
+            Event::Select,
+            Event::Write(vec![0x8F]),
+            Event::Read(1),
+            Event::Flush
+        ]
+    );
+}
@@ -0,0 +1,239 @@
+mod common;
+
+use common::{block_on, default_config, MockBus, MockDelay};
+use lis3dh_driver::registers::status_reg::DataStatus;
+use lis3dh_driver::registers::status_reg_aux::AuxStatus;
+use lis3dh_driver::{Error, HealthReport, Lis3dh};
+
+#[test]
+fn data_status_decodes_all_axes_ready() {
+    assert_eq!(
+        DataStatus::from(0b0000_1111),
+        DataStatus {
+            x_ready: true,
+            y_ready: true,
+            z_ready: true,
+            zyx_ready: true,
+            x_overrun: false,
+            y_overrun: false,
+            z_overrun: false,
+            zyx_overrun: false,
+        }
+    );
+}
+
+#[test]
+fn data_status_decodes_z_overrun() {
+    assert_eq!(
+        DataStatus::from(0b1100_1100),
+        DataStatus {
+            x_ready: false,
+            y_ready: false,
+            z_ready: true,
+            zyx_ready: true,
+            x_overrun: false,
+            y_overrun: false,
+            z_overrun: true,
+            zyx_overrun: true,
+        }
+    );
+}
+
+/// A single 7-byte burst from `STATUS_REG` decodes into the status and the 10-bit vector.
+#[test]
+fn read_status_and_accel_decodes_burst() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x27..0x2E].copy_from_slice(&[
+        0b1000_1111,
+        0x40,
+        0x00,
+        0xC0,
+        0xFF,
+        0xC0,
+        0x7F,
+    ]);
+
+    let Ok((status, vector)) = block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        lis3dh.read_status_and_accel().await
+    }) else {
+        panic!("read failed");
+    };
+
+    assert!(status.zyx_ready && status.zyx_overrun && !status.x_overrun);
+    assert_eq!(
+        [vector.x.value, vector.y.value, vector.z.value],
+        [1, -1, 511]
+    );
+}
+
+/// `read_accel_averaged` waits for data-ready, averages `n` samples and rejects `n == 0`.
+#[test]
+fn read_accel_averaged_returns_mean_and_rejects_zero() {
+    let (bus, state) = MockBus::new();
+    let mut delay = MockDelay::default();
+    state.borrow_mut().registers[0x27..0x2E].copy_from_slice(&[
+        0b0000_1000,
+        0xC0,
+        0x7F,
+        0x00,
+        0x80,
+        0x40,
+        0x00,
+    ]);
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+
+        let Ok(average) = lis3dh.read_accel_averaged(u8::MAX, &mut delay).await else {
+            panic!("read_accel_averaged failed");
+        };
+        assert_eq!(
+            [average.x.value, average.y.value, average.z.value],
+            [511, -512, 1]
+        );
+        assert!(matches!(
+            lis3dh.read_accel_averaged(0, &mut delay).await,
+            Err(Error::InvalidArgument)
+        ));
+    });
+}
+
+/// `is_data_ready` reads only `ZYXDA` of `STATUS_REG` and `is_fifo_watermark_reached` only `WTM` of `FIFO_SRC_REG`.
+#[test]
+fn polling_shortcuts_read_their_own_bits() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+
+        state.borrow_mut().registers[0x27] = 0b1111_0111;
+        state.borrow_mut().registers[0x2F] = 0b0111_1111;
+        assert!(matches!(lis3dh.is_data_ready().await, Ok(false)));
+        assert!(matches!(
+            lis3dh.is_fifo_watermark_reached().await,
+            Ok(false)
+        ));
+
+        state.borrow_mut().registers[0x27] = 0b0000_1000;
+        state.borrow_mut().registers[0x2F] = 0b1000_0000;
+        assert!(matches!(lis3dh.is_data_ready().await, Ok(true)));
+        assert!(matches!(lis3dh.is_fifo_watermark_reached().await, Ok(true)));
+    });
+}
+
+/// `read_all_status` reads `STATUS_REG_AUX` and `STATUS_REG` with two single-byte reads and decodes both.
+#[test]
+fn read_all_status_decodes_both_status_registers() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().registers[0x07] = 0b0100_0100;
+        state.borrow_mut().registers[0x27] = 0b0000_1111;
+        state.borrow_mut().reads.clear();
+
+        let Ok((aux_status, data_status)) = lis3dh.read_all_status().await else {
+            panic!("read_all_status failed");
+        };
+        assert_eq!(
+            aux_status,
+            AuxStatus {
+                adc1_ready: false,
+                adc2_ready: false,
+                adc3_ready: true,
+                all_ready: false,
+                adc1_overrun: false,
+                adc2_overrun: false,
+                adc3_overrun: true,
+                all_overrun: false,
+            }
+        );
+        assert_eq!(data_status, DataStatus::from(0b0000_1111));
+        assert!(data_status.zyx_ready);
+    });
+    assert_eq!(state.borrow().reads, [(0x07, 1), (0x27, 1)]);
+}
+
+/// Runs `self_check` on a freshly configured mock after `setup` adjusts its registers.
+fn self_check_with(setup: impl FnOnce(&mut [u8; 0x40])) -> HealthReport {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().registers[0x27] = 0b0000_1000; // ZYXDA
+        setup(&mut state.borrow_mut().registers);
+
+        let Ok(report) = lis3dh.self_check().await else {
+            panic!("self_check failed");
+        };
+        report
+    })
+}
+
+#[test]
+fn self_check_reports_healthy_device() {
+    let report = self_check_with(|_| {});
+
+    assert_eq!(
+        report,
+        HealthReport {
+            device_id_valid: true,
+            ctrl_reg0_valid: true,
+            data_updating: true,
+            fifo_overrun: false,
+        }
+    );
+    assert!(report.is_healthy());
+}
+
+#[test]
+fn self_check_flags_wrong_device_id() {
+    let report = self_check_with(|registers| registers[0x0F] = 0x44);
+
+    assert!(!report.device_id_valid);
+    assert!(!report.is_healthy());
+}
+
+#[test]
+fn self_check_flags_cleared_ctrl_reg0_bits() {
+    let report = self_check_with(|registers| registers[0x1E] = 0x00);
+
+    assert!(!report.ctrl_reg0_valid);
+    assert!(!report.is_healthy());
+}
+
+#[test]
+fn self_check_flags_fifo_overrun() {
+    let report = self_check_with(|registers| registers[0x2F] = 0b0101_1111); // OVRN_FIFO
+
+    assert!(report.fifo_overrun);
+    assert!(!report.is_healthy());
+}
+
+/// With no new data in `STATUS_REG`, e.g. checked right after reading a still sensor, the report is unhealthy even though the device is fine.
+#[test]
+fn self_check_reports_no_new_data_as_not_updating() {
+    let report = self_check_with(|registers| registers[0x27] = 0x00);
+
+    assert_eq!(
+        report,
+        HealthReport {
+            device_id_valid: true,
+            ctrl_reg0_valid: true,
+            data_updating: false,
+            fifo_overrun: false,
+        }
+    );
+    assert!(!report.is_healthy());
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "stream")]
+
+mod common;
+
+use std::cell::Cell;
+use std::convert::Infallible;
+use std::future::poll_fn;
+use std::pin::pin;
+use std::rc::Rc;
+use std::task::Poll;
+
+use common::{block_on, default_config, MockBus, MockDelay};
+use embedded_hal::digital::ErrorType;
+use embedded_hal_async::digital::Wait;
+use futures_util::StreamExt;
+use lis3dh_driver::{Event, Lis3dh};
+
+/// An active-high interrupt pin whose level the test sets through a shared flag; waiting for high pends until it is set.
+struct MockPin {
+    high: Rc<Cell<bool>>,
+}
+
+impl ErrorType for MockPin {
+    type Error = Infallible;
+}
+
+impl Wait for MockPin {
+    async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+        poll_fn(|_| {
+            if self.high.get() {
+                Poll::Ready(Ok(()))
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+        unimplemented!()
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+        unimplemented!()
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+        unimplemented!()
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+        unimplemented!()
+    }
+}
+
+/// The stream waits for `ZYXDA` or `ZYXOR` before reading each sample.
+#[test]
+fn stream_yields_sample_per_ready_event() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[0x40, 0x00, 0x80, 0x00, 0x00, 0x40]);
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        let mut samples = pin!(lis3dh.into_sample_stream(MockDelay::default()));
+
+        state.borrow_mut().registers[0x27] = 0b0000_1000;
+        let Some(Ok(sample)) = samples.next().await else {
+            panic!("no sample");
+        };
+        assert_eq!(
+            [sample.x.value, sample.y.value, sample.z.value],
+            [1, 2, 256]
+        );
+
+        // An overrun without data ready is read anyway.
+        state.borrow_mut().registers[0x27] = 0b1000_0000;
+        assert!(matches!(samples.next().await, Some(Ok(_))));
+    });
+}
+
+/// INT1 yields the sample and INT2 the decoded `INT2_SRC`; when both are asserted, data-ready comes first and the latched motion event next.
+#[test]
+fn event_stream_services_each_pin() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x28..0x2E].copy_from_slice(&[0x40, 0x00, 0x80, 0x00, 0x00, 0x40]);
+    state.borrow_mut().registers[0x35] = 0b0110_0000;
+    let int1 = Rc::new(Cell::new(true));
+    let int2 = Rc::new(Cell::new(true));
+
+    block_on(async {
+        let Ok(lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+        state.borrow_mut().reads.clear();
+        let mut events = pin!(lis3dh.event_stream(
+            MockPin { high: int1.clone() },
+            MockPin { high: int2.clone() }
+        ));
+
+        let Some(Ok(Event::DataReady(sample))) = events.next().await else {
+            panic!("no data-ready event");
+        };
+        assert_eq!(
+            [sample.x.value, sample.y.value, sample.z.value],
+            [1, 2, 256]
+        );
+
+        // Reading the sample released INT1, while the latched INT2 is still asserted.
+        int1.set(false);
+        let Some(Ok(Event::Motion(source))) = events.next().await else {
+            panic!("no motion event");
+        };
+        assert!(source.active && source.z_high);
+    });
+
+    assert_eq!(state.borrow().reads, [(0x25, 1), (0x28, 6), (0x35, 1)]);
+}
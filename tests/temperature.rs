@@ -0,0 +1,47 @@
+mod common;
+
+use common::{block_on, MockBus};
+use lis3dh_driver::config::Config;
+use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg};
+use lis3dh_driver::Lis3dh;
+
+/// Reads the temperature in normal (10-bit) mode with OUT_ADC3 holding `raw`.
+fn read_temperature(raw: i16) -> f32 {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x0C..0x0E].copy_from_slice(&raw.to_le_bytes());
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(
+            bus,
+            Config {
+                data_rate: ctrl_reg1::odr::F100Hz,
+                power_mode: ctrl_reg1::lp_en::NormalPowerMode,
+                axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+                full_scale: ctrl_reg4::fs::S2G,
+                resolution_mode: ctrl_reg4::hr::NormalResolution,
+                block_data_update: ctrl_reg4::bdu::BlockDataUpdate,
+                endianness: ctrl_reg4::ble::LittleEndian,
+                adc_enable: temp_cfg_reg::adc_en::AdcEnabled,
+                temperature_enable: temp_cfg_reg::temp_en::TempEnabled,
+                fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+                fifo_mode: fifo_ctrl_reg::fm::Bypass,
+                fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+            },
+        )
+        .await
+        else {
+            panic!("new failed");
+        };
+        let Ok(temperature) = lis3dh.read_temperature().await else {
+            panic!("read failed");
+        };
+        temperature
+    })
+}
+
+#[test]
+fn temperature_is_relative_to_25_celsius() {
+    assert_eq!(read_temperature(0x0000), 25.0);
+    assert_eq!(read_temperature(0x0100), 26.0);
+    assert_eq!(read_temperature(-0x0040), 24.75);
+}
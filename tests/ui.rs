@@ -0,0 +1,7 @@
+//! Compile-fail tests proving the entitlements reject invalid configs.
+
+#[test]
+fn entitlement_violations_fail_to_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
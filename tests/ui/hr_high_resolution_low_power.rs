@@ -0,0 +1,21 @@
+// High resolution (12-bit) is only available in normal power mode.
+
+use lis3dh_driver::config::Config;
+use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4, ctrl_reg5, fifo_ctrl_reg, temp_cfg_reg};
+
+fn main() {
+    let _config = Config {
+        data_rate: ctrl_reg1::odr::F100Hz,
+        power_mode: ctrl_reg1::lp_en::LowPowerMode,
+        axis_enable: ctrl_reg1::axis_enable::XYZEnabled,
+        full_scale: ctrl_reg4::fs::S2G,
+        resolution_mode: ctrl_reg4::hr::HighResolution,
+        block_data_update: ctrl_reg4::bdu::ContinuousDataUpdate,
+        endianness: ctrl_reg4::ble::LittleEndian,
+        adc_enable: temp_cfg_reg::adc_en::AdcDisabled,
+        temperature_enable: temp_cfg_reg::temp_en::TempDisabled,
+        fifo_enable: ctrl_reg5::fifo_en::FifoDisabled,
+        fifo_mode: fifo_ctrl_reg::fm::Bypass,
+        fifo_watermark: fifo_ctrl_reg::fth::Watermark::<0>,
+    };
+}
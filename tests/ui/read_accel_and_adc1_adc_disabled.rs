@@ -0,0 +1,11 @@
+// ADC channel 1 only holds conversions with the ADC enabled, so it can't be read alongside the acceleration otherwise.
+
+use lis3dh_driver::bus::Lis3dhBus;
+use lis3dh_driver::config::presets::Default100Hz2G;
+use lis3dh_driver::Lis3dh;
+
+async fn read_with_adc_disabled<Bus: Lis3dhBus>(lis3dh: &mut Lis3dh<Bus, Default100Hz2G>) {
+    let _ = lis3dh.read_accel_and_adc1().await;
+}
+
+fn main() {}
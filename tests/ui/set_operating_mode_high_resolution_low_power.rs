@@ -0,0 +1,19 @@
+// The driver can't be switched to an operating mode that combines high resolution with low power mode.
+
+use lis3dh_driver::bus::Lis3dhBus;
+use lis3dh_driver::config::presets::Default100Hz2G;
+use lis3dh_driver::properties::operating_mode::OperatingMode;
+use lis3dh_driver::registers::{ctrl_reg1, ctrl_reg4};
+use lis3dh_driver::Lis3dh;
+
+async fn switch_to_invalid_mode<Bus: Lis3dhBus>(lis3dh: Lis3dh<Bus, Default100Hz2G>) {
+    let _ = lis3dh
+        .set_operating_mode(OperatingMode {
+            data_rate: ctrl_reg1::odr::F100Hz,
+            power_mode: ctrl_reg1::lp_en::LowPowerMode,
+            resolution_mode: ctrl_reg4::hr::HighResolution,
+        })
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// A threshold built at ±16 g can't be written to a lis3dh configured for ±2 g, where the same count means a different acceleration.
+
+use lis3dh_driver::bus::Lis3dhBus;
+use lis3dh_driver::config::presets::Default100Hz2G;
+use lis3dh_driver::properties::threshold_coefficient::Threshold;
+use lis3dh_driver::registers::ctrl_reg4::fs::S16G;
+use lis3dh_driver::Lis3dh;
+
+async fn reuse_16g_threshold<Bus: Lis3dhBus>(lis3dh: &mut Lis3dh<Bus, Default100Hz2G>) {
+    let threshold = Threshold::<S16G>::from_mg(250);
+    let _ = lis3dh.set_int1_threshold(threshold).await;
+}
+
+fn main() {}
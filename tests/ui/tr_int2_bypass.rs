@@ -0,0 +1,12 @@
+// The trigger selection only has an effect in stream-to-FIFO mode, so interrupt generator 2 can't be selected in bypass mode.
+
+use lis3dh_driver::bus::Lis3dhBus;
+use lis3dh_driver::config::presets::Default100Hz2G;
+use lis3dh_driver::registers::fifo_ctrl_reg::tr;
+use lis3dh_driver::Lis3dh;
+
+async fn select_int2_trigger<Bus: Lis3dhBus>(lis3dh: &mut Lis3dh<Bus, Default100Hz2G>) {
+    let _ = lis3dh.set_fifo_trigger(tr::Int2).await;
+}
+
+fn main() {}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{block_on, default_config, MockBus};
+use lis3dh_driver::registers::who_am_i::DeviceId;
+use lis3dh_driver::{Error, Lis3dh};
+
+/// `new` refuses a device with the wrong `WHO_AM_I` and leaves it unconfigured.
+#[test]
+fn new_rejects_wrong_device_id() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x0F] = 0x44;
+
+    let result = block_on(Lis3dh::new(bus, default_config()));
+
+    assert!(matches!(result, Err(Error::InvalidDevice { found: 0x44 })));
+    assert!(state.borrow().writes.is_empty());
+}
+
+/// `new_unchecked` configures the device regardless of its `WHO_AM_I`.
+#[test]
+fn new_unchecked_skips_device_id_check() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x0F] = 0x44;
+
+    let result = block_on(Lis3dh::new_unchecked(bus, default_config()));
+
+    assert!(result.is_ok());
+    assert_eq!(state.borrow().writes.len(), 2);
+}
+
+/// `Error` is `Debug` whenever the bus error is, so failures print usefully from `unwrap` and `?`.
+#[test]
+fn error_debug_prints_variant_and_fields() {
+    let (bus, state) = MockBus::new();
+    state.borrow_mut().registers[0x0F] = 0x44;
+
+    let Err(error) = block_on(Lis3dh::new(bus, default_config())) else {
+        panic!("new accepted the wrong device");
+    };
+
+    assert_eq!(format!("{error:?}"), "InvalidDevice { found: 68 }");
+    assert_eq!(format!("{:?}", Error::Bus("nack")), "Bus(\"nack\")");
+}
+
+/// `read_device_id` wraps `WHO_AM_I` in a `DeviceId` that recognizes a lis3dh and prints as hex.
+#[test]
+fn read_device_id_identifies_lis3dh() {
+    let (bus, state) = MockBus::new();
+
+    block_on(async {
+        let Ok(mut lis3dh) = Lis3dh::new(bus, default_config()).await else {
+            panic!("new failed");
+        };
+
+        let Ok(id) = lis3dh.read_device_id().await else {
+            panic!("read_device_id failed");
+        };
+        assert!(id.is_lis3dh());
+        assert_eq!(id.to_string(), "0x33");
+
+        state.borrow_mut().registers[0x0F] = 0x44;
+        assert!(matches!(
+            lis3dh.read_device_id().await,
+            Ok(id) if !id.is_lis3dh()
+        ));
+    });
+
+    assert_eq!(DeviceId(0x0A).to_string(), "0x0a");
+}